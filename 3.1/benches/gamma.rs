@@ -0,0 +1,36 @@
+//! Gamma and epsilon worked out reading by reading, against popcounts over the columns
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day3_1::{calc_gamma_epsilon, calc_gamma_epsilon_columns, Columns, DiagReport, Val};
+
+/// A report of random readings, from a fixed seed so every run measures the same one
+fn report(len: usize, width: usize) -> DiagReport {
+    let mut state: u64 = 0x2021;
+    let mut bit = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        state >> 63 == 1
+    };
+    let data = (0..len).map(|_| Val::from_bits((0..width).map(|_| bit()))).collect();
+    DiagReport { width, data }
+}
+
+fn gamma_epsilon(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gamma_epsilon");
+    group.sample_size(10);
+    for (len, width) in [(1_000, 12), (100_000, 12), (1_000_000, 12), (100_000, 100)] {
+        let report = report(len, width);
+        let columns = Columns::from(&report);
+        let size = format!("{}x{}", len, width);
+        group.throughput(Throughput::Elements(len as u64));
+        group.bench_with_input(BenchmarkId::new("rows", &size), &report, |b, report| {
+            b.iter(|| calc_gamma_epsilon(report))
+        });
+        group.bench_with_input(BenchmarkId::new("columns", &size), &columns, |b, columns| {
+            b.iter(|| calc_gamma_epsilon_columns(columns))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, gamma_epsilon);
+criterion_main!(benches);
@@ -0,0 +1,158 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::Read;
+use std::str::FromStr;
+use bitword::BitWord;
+use parsing::{ParseError, Token};
+
+pub type Val = BitWord;
+pub type Data = Vec<Val>;
+#[derive(Debug, PartialEq, Eq)]
+pub struct DiagReport {
+    pub width: usize,
+    pub data: Data,
+}
+
+
+impl Display for DiagReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for val in &self.data {
+            writeln!(f, "{}", val)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DiagReport {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut data = Vec::new();
+        let mut width = 0;
+        for line in parsing::lines(s) {
+            let token = line.token();
+            let val = parse_reading(&token, if data.is_empty() { None } else { Some(width) })?;
+            width = token.text.len();
+            data.push(val);
+        }
+        Ok(DiagReport { data, width })
+    }
+}
+
+/// Reads a reading of binary digits that, when known, has the report's width
+fn parse_reading(token: &Token, width: Option<usize>) -> Result<Val, ParseError> {
+    let val = Val::from_str(token.text).map_err(|e| token.char_error(e.offset, "expected a binary digit"))?;
+    match width {
+        Some(width) if token.text.len() != width => Err(token.error(format!("expected {} bits", width))),
+        _ => Ok(val),
+    }
+}
+
+
+impl DiagReport {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// Reads the whole input from any reader, such as stdin
+    pub fn from_reader<R: Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        Ok(Self::from_str(&data)?)
+    }
+}
+
+
+pub fn read_file(path: &str) -> anyhow::Result<DiagReport> {
+    DiagReport::from_file(path)
+}
+
+
+/// The most common bit in each position, and the least common, which is the same word flipped
+pub fn calc_gamma_epsilon(report: &DiagReport) -> (Val, Val) {
+    let gamma_threshold = report.data.len() / 2;
+    let mut accum = vec![0; report.width];
+    for report_val in &report.data {
+        for (count, bit) in accum.iter_mut().zip(report_val.iter()) {
+            *count += bit as usize;
+        }
+    }
+    let gamma = Val::from_bits(accum.iter().map(|&count| count > gamma_threshold));
+    let epsilon = !&gamma;
+    (gamma, epsilon)
+}
+
+
+/// A report stored a column at a time: bit `i` of every reading is packed into column `i`, so the
+/// ones in a position are counted a block of 64 readings at a time rather than reading by reading
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Columns {
+    /// How many readings there are
+    pub len: usize,
+    pub columns: Vec<BitWord>,
+}
+
+impl Columns {
+    /// Adds a reading of the report's width
+    fn push(&mut self, reading: &Val) {
+        if self.len == 0 {
+            self.columns = vec![BitWord::default(); reading.width()];
+        }
+        for (column, bit) in self.columns.iter_mut().zip(reading.iter()) {
+            column.push(bit);
+        }
+        self.len += 1;
+    }
+
+    /// Reads the report a line at a time, without ever holding it as text or as rows
+    pub fn from_reader<R: Read>(reader: R) -> anyhow::Result<Self> {
+        let mut columns = Columns::default();
+        for line in parsing::read_lines(reader) {
+            let line = line?;
+            let width = (columns.len > 0).then_some(columns.columns.len());
+            columns.push(&parse_reading(&line.line().token(), width)?);
+        }
+        Ok(columns)
+    }
+}
+
+/// Turns the report around by setting each reading's ones in place, skipping its zeros
+impl From<&DiagReport> for Columns {
+    fn from(report: &DiagReport) -> Self {
+        let len = report.data.len();
+        let mut columns = vec![BitWord::zeros(len); report.width];
+        for (row, reading) in report.data.iter().enumerate() {
+            for (block, &bits) in reading.blocks().iter().enumerate() {
+                let mut bits = bits;
+                while bits != 0 {
+                    if let Some(column) = columns.get_mut(block * 64 + bits.trailing_zeros() as usize) {
+                        column.set(row, true);
+                    }
+                    bits &= bits - 1;
+                }
+            }
+        }
+        Columns { len, columns }
+    }
+}
+
+impl FromStr for Columns {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut columns = Columns::default();
+        for line in parsing::lines(s) {
+            let width = (columns.len > 0).then_some(columns.columns.len());
+            columns.push(&parse_reading(&line.token(), width)?);
+        }
+        Ok(columns)
+    }
+}
+
+/// [`calc_gamma_epsilon`] by popcounts over the columns
+pub fn calc_gamma_epsilon_columns(report: &Columns) -> (Val, Val) {
+    let gamma_threshold = report.len / 2;
+    let gamma = Val::from_bits(report.columns.iter().map(|column| column.count_ones() > gamma_threshold));
+    let epsilon = !&gamma;
+    (gamma, epsilon)
+}
@@ -0,0 +1,43 @@
+use std::str::FromStr;
+use proptest::prelude::*;
+use day3_1::{calc_gamma_epsilon, calc_gamma_epsilon_columns, Columns, DiagReport, Val};
+
+const SAMPLE: &str = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+
+#[test]
+fn both_strategies_solve_the_sample() {
+    let report = DiagReport::from_str(SAMPLE).unwrap();
+    let (gamma, epsilon) = calc_gamma_epsilon(&report);
+    assert_eq!((gamma.to_u64(), epsilon.to_u64()), (Some(22), Some(9)));
+    let columns = Columns::from_str(SAMPLE).unwrap();
+    assert_eq!(columns.len, 12);
+    assert_eq!(columns.columns[0].to_string(), "011110011100");
+    assert_eq!(calc_gamma_epsilon_columns(&columns), (gamma, epsilon));
+}
+
+#[test]
+fn columns_are_checked_like_reports() {
+    assert_eq!(
+        Columns::from_str("0101\n011\n").unwrap_err().to_string(),
+        DiagReport::from_str("0101\n011\n").unwrap_err().to_string()
+    );
+    assert!(Columns::from_reader("01\n2\n".as_bytes()).is_err());
+}
+
+fn report() -> impl Strategy<Value = DiagReport> {
+    (1..150usize).prop_flat_map(|width| {
+        prop::collection::vec(prop::collection::vec(any::<bool>(), width).prop_map(Val::from_bits), 1..200)
+            .prop_map(move |data| DiagReport { width, data })
+    })
+}
+
+proptest! {
+    #[test]
+    fn columns_count_like_rows(report in report()) {
+        let text = report.to_string();
+        let columns = Columns::from(&report);
+        prop_assert_eq!(&Columns::from_str(&text).unwrap(), &columns);
+        prop_assert_eq!(&Columns::from_reader(text.as_bytes()).unwrap(), &columns);
+        prop_assert_eq!(calc_gamma_epsilon_columns(&columns), calc_gamma_epsilon(&report));
+    }
+}
@@ -0,0 +1,32 @@
+use std::str::FromStr;
+use proptest::prelude::*;
+use day3_1::{DiagReport, Val};
+
+fn report() -> impl Strategy<Value = DiagReport> {
+    (1..100usize).prop_flat_map(|width| {
+        prop::collection::vec(prop::collection::vec(any::<bool>(), width).prop_map(Val::from_bits), 1..50)
+            .prop_map(move |data| DiagReport { width, data })
+    })
+}
+
+proptest! {
+    #[test]
+    fn parsing_never_panics(s in "\\PC*") {
+        let _ = DiagReport::from_str(&s);
+    }
+
+    #[test]
+    fn parsing_report_like_lines_never_panics(s in "([01 x]{0,70}\n){0,10}") {
+        let _ = DiagReport::from_str(&s);
+    }
+
+    #[test]
+    fn reading_arbitrary_bytes_never_panics(bytes in any::<Vec<u8>>()) {
+        let _ = DiagReport::from_reader(&bytes[..]);
+    }
+
+    #[test]
+    fn report_round_trips(report in report()) {
+        prop_assert_eq!(DiagReport::from_str(&report.to_string()).unwrap(), report);
+    }
+}
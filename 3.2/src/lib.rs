@@ -0,0 +1,72 @@
+use anyhow::{anyhow, bail};
+
+pub use day3_1::{calc_gamma_epsilon, Data, DiagReport, Val};
+
+/// The reading left by keeping the most common bit in each position, 1 on a tie
+pub fn o2_scrubber_rating(report: &DiagReport) -> anyhow::Result<&Val> {
+    if report.data.is_empty() {
+        bail!("the report has no readings to rate");
+    }
+    let mut haystack: Vec<&Val> = report.data.iter().collect();
+    for pointer in 0..report.width {
+        let col_count: usize = haystack.iter().map(|v| v[pointer] as usize).sum();
+        let mode = col_count >= (div_ciel(haystack.len(), 2));
+        drain_filter(&mut haystack, |v| v[pointer] != mode);
+        if haystack.len() == 1 {
+            return Ok(haystack[0])
+        }
+    }
+    Ok(haystack[0])
+}
+
+/// The reading left by keeping the least common bit in each position, 0 on a tie
+pub fn co2_scrubber_rating(report: &DiagReport) -> anyhow::Result<&Val> {
+    if report.data.is_empty() {
+        bail!("the report has no readings to rate");
+    }
+    let mut haystack: Vec<&Val> = report.data.iter().collect();
+    for pointer in 0..report.width {
+        let col_count: usize = haystack.iter().map(|v| v[pointer] as usize).sum();
+        // when every reading has the same bit here there is no less common one to keep, so
+        // keep them all rather than none
+        if col_count == 0 || col_count == haystack.len() {
+            continue;
+        }
+        let mode = col_count >= (div_ciel(haystack.len(), 2));
+        drain_filter(&mut haystack, |v| v[pointer] == mode);
+        if haystack.len() == 1 {
+            return Ok(haystack[0])
+        }
+    }
+    Ok(haystack[0])
+}
+
+/// The two ratings multiplied, which always fits when each fits in 64 bits
+pub fn life_support_rating(report: &DiagReport) -> anyhow::Result<u128> {
+    let too_wide = || anyhow!("ratings wider than 64 bits don't fit in a number");
+    let o2_rating = o2_scrubber_rating(report)?.to_u64().ok_or_else(too_wide)?;
+    let co2_rating = co2_scrubber_rating(report)?.to_u64().ok_or_else(too_wide)?;
+    Ok(o2_rating as u128 * co2_rating as u128)
+}
+
+/// Removes elements from the vector where the filter evaluates to true
+fn drain_filter<T, F>(vec: &mut Vec<T>, mut filter: F)
+    where F: FnMut(&mut T) -> bool
+{
+    let mut i = 0;
+    while i < vec.len() {
+        if filter(&mut vec[i]) {
+            vec.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+fn div_ciel(a: usize, b: usize) -> usize {
+    if a.is_multiple_of(b) {
+        a / b
+    } else {
+        (a / b) + 1
+    }
+}
@@ -0,0 +1,25 @@
+use std::str::FromStr;
+use day3_2::{co2_scrubber_rating, life_support_rating, o2_scrubber_rating, DiagReport};
+
+#[test]
+fn rates_the_sample() {
+    let report = DiagReport::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/sample.txt")).unwrap();
+    assert_eq!(o2_scrubber_rating(&report).unwrap().to_string(), "10111");
+    assert_eq!(co2_scrubber_rating(&report).unwrap().to_string(), "01010");
+    assert_eq!(life_support_rating(&report).unwrap(), 230);
+}
+
+#[test]
+fn an_empty_report_has_no_ratings() {
+    let report = DiagReport::from_str("").unwrap();
+    assert!(o2_scrubber_rating(&report).is_err());
+    assert!(life_support_rating(&report).is_err());
+}
+
+#[test]
+fn wide_ratings_multiply_without_overflowing() {
+    let ones = "1".repeat(40);
+    let report = DiagReport::from_str(&format!("{}\n0{}\n", ones, &ones[1..])).unwrap();
+    let (o2, co2) = ((1u128 << 40) - 1, (1u128 << 39) - 1);
+    assert_eq!(life_support_rating(&report).unwrap(), o2 * co2);
+}
@@ -0,0 +1,178 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::Read;
+use std::str::FromStr;
+use std::collections::HashSet;
+use parsing::{Line, ParseError};
+
+pub type Cell = u8;
+
+pub type Board = Vec<Vec<Cell>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub call_pointer: usize,
+    pub calls: Vec<Cell>,
+    pub boards: Vec<Board>,
+}
+
+pub struct Win<'a> {
+    pub board: &'a Board,
+    pub calls: &'a [Cell],
+    pub winning_number: &'a Cell,
+    pub index: usize,
+    pub score: usize,
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let calls: Vec<String> = self.calls.iter().map(Cell::to_string).collect();
+        writeln!(f, "{}", calls.join(","))?;
+        for board in &self.boards {
+            writeln!(f)?;
+            for row in board {
+                writeln!(f, "{}", fmt_cells(row))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = parsing::lines(s);
+        let call_line = lines.next().unwrap_or(Line::new(1, ""));
+        if call_line.is_blank() {
+            return Err(call_line.error("expected a line of calls"));
+        }
+        let calls = call_line.split(",").map(|t| t.parse::<Cell>()).collect::<Result<Vec<_>, _>>()?;
+        // discard empty line after call line
+        if let Some(line) = lines.next().filter(|l| !l.is_blank()) {
+            return Err(line.error("expected a blank line after the calls"));
+        }
+        let mut board = Vec::new();
+        let mut boards = Vec::new();
+        for line in lines {
+            if line.is_blank() {
+                if !board.is_empty() {
+                    boards.push(board);
+                }
+                board = Vec::new();
+                continue
+            }
+            let row = line.tokens().map(|t| t.parse::<Cell>()).collect::<Result<Vec<_>, _>>()?;
+            board.push(row)
+        }
+        if !board.is_empty() {
+            boards.push(board);
+        }
+        Ok(Game { calls, boards, call_pointer: 0 })
+    }
+}
+
+impl Game {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// Reads the whole input from any reader, such as stdin
+    pub fn from_reader<R: Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        Ok(Self::from_str(&data)?)
+    }
+
+    pub fn called(&self) -> &[Cell] {
+        &self.calls[0..self.call_pointer]
+    }
+
+    // plays all boards returning the calls and winning board
+    pub fn play<'a>(&'a mut self) -> Option<Win<'a>> {
+        while self.call_pointer < self.calls.len() {
+            for (i, board) in self.boards.iter().enumerate() {
+                if win(self.called(), board).is_some() {
+                    let calls = self.called();
+                    return Some(Win {
+                        board,
+                        calls,
+                        winning_number: &calls[self.call_pointer - 1],
+                        index: i,
+                        score: score(calls, board),
+                    })
+                }
+            }
+            self.call_pointer += 1;
+        }
+        None
+    }
+
+    /// Removes each winning board in turn until only one is left, then plays out the last board
+    pub fn play_last(&mut self) -> Option<Win<'_>> {
+        while self.boards.len() > 1 {
+            let index = self.play()?.index;
+            self.boards.remove(index);
+        }
+        self.play()
+    }
+}
+
+/// Determines if the board wins with the given calls. Returns the winning row or column numbers.
+pub fn win(calls: &[Cell], board: &Board) -> Option<Vec<Cell>> {
+    let calls: HashSet<Cell> = HashSet::from_iter(calls.iter().copied());
+    for row in board {
+        let cells: HashSet<Cell> = HashSet::from_iter(row.iter().copied());
+        if calls.intersection(&cells).count() == cells.len() {
+            return Some(row.clone())
+        }
+    }
+
+    for col in cols(board) {
+        let cells: HashSet<Cell> = HashSet::from_iter(col.iter().copied());
+        if calls.intersection(&cells).count() == cells.len() {
+            return Some(col.clone())
+        }
+    }
+    None
+}
+
+/// Swaps row and columns for the given board
+pub fn cols(board: &Board) -> Board {
+    let mut cols = Vec::new();
+    for row in board {
+        for (i, cell) in row.iter().enumerate() {
+            if i == cols.len() {
+                cols.push(Vec::new());
+            }
+            cols[i].push(*cell);
+        }
+    }
+    cols
+}
+
+pub fn score(calls: &[Cell], board: &Board) -> usize {
+    let last_call = calls[calls.len() - 1] as usize;
+    let calls: HashSet<Cell> = HashSet::from_iter(calls.iter().copied());
+    let unused_cells = board.iter().flatten().filter(|i| !calls.contains(i));
+    unused_cells.map(|i| *i as usize).sum::<usize>() * last_call
+}
+
+pub fn fmt_cells(cells: &[Cell]) -> String {
+    let mut output = String::new();
+    for i in cells {
+        output.push_str(&format!("{:>2} ", &i));
+    }
+    output.pop();
+    output
+}
+
+pub fn fmt_board(board: &Board) -> String {
+    let mut output = String::new();
+    for row in board {
+        output.push_str(&fmt_cells(row));
+        output.push('\n');
+    }
+    output.pop();
+    output
+}
@@ -0,0 +1,34 @@
+use std::str::FromStr;
+use proptest::prelude::*;
+use day4_2::{Board, Game};
+
+fn board() -> impl Strategy<Value = Board> {
+    prop::collection::vec(prop::collection::vec(any::<u8>(), 1..6), 1..6)
+}
+
+fn game() -> impl Strategy<Value = Game> {
+    (prop::collection::vec(any::<u8>(), 1..30), prop::collection::vec(board(), 0..5))
+        .prop_map(|(calls, boards)| Game { call_pointer: 0, calls, boards })
+}
+
+proptest! {
+    #[test]
+    fn parsing_never_panics(s in "\\PC*") {
+        let _ = Game::from_str(&s);
+    }
+
+    #[test]
+    fn parsing_game_like_lines_never_panics(s in "[0-9,]{0,20}\n(( *[0-9]{0,4}){0,6}\n){0,12}") {
+        let _ = Game::from_str(&s);
+    }
+
+    #[test]
+    fn reading_arbitrary_bytes_never_panics(bytes in any::<Vec<u8>>()) {
+        let _ = Game::from_reader(&bytes[..]);
+    }
+
+    #[test]
+    fn game_round_trips(game in game()) {
+        prop_assert_eq!(Game::from_str(&game.to_string()).unwrap(), game);
+    }
+}
@@ -0,0 +1,8 @@
+use std::str::FromStr;
+use day5_1::Map;
+
+#[test]
+fn long_segments_are_counted() {
+    let map = Map::from_str("0,5 -> 20000,5\n0,5 -> 20000,5\n").unwrap();
+    assert_eq!(map.overlaps().unwrap().len(), 20001);
+}
@@ -0,0 +1,40 @@
+use std::str::FromStr;
+use proptest::prelude::*;
+use day5_1::{Map, Point, Segment};
+
+fn point() -> impl Strategy<Value = Point> {
+    (any::<usize>(), any::<usize>()).prop_map(|(x, y)| Point(x, y))
+}
+
+fn segment() -> impl Strategy<Value = Segment> {
+    (point(), point()).prop_map(|(a, b)| Segment(a, b))
+}
+
+proptest! {
+    #[test]
+    fn parsing_never_panics(s in "\\PC*") {
+        let _ = Segment::from_str(&s);
+        let _ = Map::from_str(&s);
+    }
+
+    #[test]
+    fn parsing_segment_like_lines_never_panics(s in "( *-?[0-9]{0,21} *,? *[0-9]{0,21} *(->)? *[0-9]{0,3},?[0-9]{0,3} *\n){0,10}") {
+        let _ = Map::from_str(&s);
+    }
+
+    #[test]
+    fn reading_arbitrary_bytes_never_panics(bytes in any::<Vec<u8>>()) {
+        let _ = Map::from_reader(&bytes[..]);
+    }
+
+    #[test]
+    fn segment_round_trips(segment in segment()) {
+        prop_assert_eq!(Segment::from_str(&segment.to_string()).unwrap(), segment);
+    }
+
+    #[test]
+    fn map_round_trips(vents in prop::collection::vec(segment(), 0..50)) {
+        let map = Map { vents };
+        prop_assert_eq!(Map::from_str(&map.to_string()).unwrap(), map);
+    }
+}
@@ -0,0 +1,172 @@
+use anyhow::anyhow as err;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::fs;
+use std::io::Read;
+use std::collections::HashMap;
+use parsing::{Line, ParseError, Token};
+
+pub enum Direction {
+    Horizontal,
+    Vertical,
+    Diagonal,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point(pub usize, pub usize);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Segment(pub Point, pub Point);
+
+impl Point {
+    /// Parses an `x,y` coordinate pair
+    pub fn parse_token(token: &Token) -> Result<Self, ParseError> {
+        let mut coords = token.split(",");
+        let x = coords.expect("an x coordinate")?.parse::<usize>()?;
+        let y = coords.expect("a y coordinate")?.parse::<usize>()?;
+        coords.finish()?;
+        Ok(Point(x, y))
+    }
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{} -> {},{}", self.0.0, self.0.1, self.1.0, self.1.1)
+    }
+}
+
+impl FromStr for Segment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_line(&Line::new(1, s))
+    }
+}
+
+impl Segment {
+    /// Parses an `x1,y1 -> x2,y2` line
+    pub fn parse_line(line: &Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        let left = Point::parse_token(&tokens.expect("a start point")?)?;
+        tokens.expect("`->`")?.expect("->")?;
+        let right = Point::parse_token(&tokens.expect("an end point")?)?;
+        tokens.finish()?;
+        Ok(Segment(left, right))
+    }
+}
+
+impl Segment {
+
+    fn _slope(a: isize, b: isize) -> isize {
+        let diff = a - b;
+        match 0.cmp(&diff) {
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+            std::cmp::Ordering::Less => -1,
+        }
+    }
+
+    pub fn slope(&self) -> (isize, isize) {
+        let x_step = Self::_slope(self.0.0 as isize, self.1.0 as isize);
+        let y_step = Self::_slope(self.0.1 as isize, self.1.1 as isize);
+        (x_step, y_step)
+    }
+
+    /// Returns every point on the segment, end to end. Fails for segments that aren't
+    /// horizontal, vertical, or at 45 degrees, which would never reach their far end
+    pub fn coords(&self) -> anyhow::Result<Vec<Point>> {
+        let (width, height) = (self.0.0.abs_diff(self.1.0), self.0.1.abs_diff(self.1.1));
+        if width != 0 && height != 0 && width != height {
+            return Err(err!("{} is not horizontal, vertical or at 45 degrees", self));
+        }
+        let point = |i| Point(Self::toward(self.0.0, self.1.0, i), Self::toward(self.0.1, self.1.1, i));
+        Ok((0..=width.max(height)).map(point).collect())
+    }
+
+    /// `i` steps from `from` in the direction of `to`, which is at least that far away
+    fn toward(from: usize, to: usize, i: usize) -> usize {
+        match from.cmp(&to) {
+            std::cmp::Ordering::Equal => from,
+            std::cmp::Ordering::Less => from + i,
+            std::cmp::Ordering::Greater => from - i,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    pub vents: Vec<Segment>,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for vent in &self.vents {
+            writeln!(f, "{}", vent)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut vents = Vec::new();
+        for line in parsing::lines(s) {
+            let vent = Segment::parse_line(&line)?;
+            vents.push(vent)
+        }
+        Ok(Map { vents })
+    }
+}
+
+impl Map {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// Reads the whole input from any reader, such as stdin
+    pub fn from_reader<R: Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        Ok(Self::from_str(&data)?)
+    }
+
+    /// Returns a set of points representing the minimum and maximum corners of the grid
+    pub fn bounds(&self) -> (Point, Point) {
+        let (mut min_x, mut min_y) = (usize::MAX, usize::MAX);
+        let (mut max_x, mut max_y) = (0, 0);
+        for segment in &self.vents {
+            let mut xs = [segment.0.0, segment.1.0];
+            let mut ys = [segment.0.1, segment.1.1];
+            xs.sort();
+            ys.sort();
+            if xs[0] < min_x {
+                min_x = xs[0];
+            }
+            if xs[1] > max_x {
+                max_x = xs[1];
+            }
+            if ys[0] < min_y {
+                min_y = ys[0];
+            }
+            if ys[1] > max_y {
+                max_y = ys[1];
+            }
+        }
+        (Point(min_x, min_y), Point(max_x, max_y))
+    }
+
+    /// Returns the set of all points where at least 1 segment overlaps
+    pub fn overlaps(&self) -> anyhow::Result<HashMap<Point, usize>> {
+        let mut counts = HashMap::new();
+        for vent in &self.vents {
+            for point in vent.coords()? {
+                *counts.entry(point).or_insert(0) += 1;
+            }
+        }
+        counts.retain(|_k, v| *v > 1);
+        Ok(counts)
+    }
+
+}
@@ -0,0 +1,14 @@
+use std::str::FromStr;
+use day5_2::Map;
+
+#[test]
+fn long_segments_are_counted() {
+    let map = Map::from_str("0,5 -> 20000,5\n0,5 -> 20000,5\n").unwrap();
+    assert_eq!(map.overlaps().unwrap().len(), 20001);
+}
+
+#[test]
+fn crooked_segments_are_an_error() {
+    let map = Map::from_str("0,0 -> 3,1\n").unwrap();
+    assert!(map.overlaps().is_err());
+}
@@ -0,0 +1,91 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::Read;
+use std::str::FromStr;
+use parsing::ParseError;
+
+const INIT_SPAWN_DAYS: i8 = 9;
+const SPAWN_DAYS: i8 = 7;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fish {
+    pub days_until_spawn: i8,
+}
+
+impl Default for Fish {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fish {
+    pub fn new() -> Self {
+        Fish { days_until_spawn: INIT_SPAWN_DAYS }
+    }
+    pub fn tick(&mut self) -> Option<Self> {
+        self.days_until_spawn -= 1;
+        if self.days_until_spawn < 0 {
+            self.days_until_spawn += SPAWN_DAYS;
+            return Some(Fish::new())
+        }
+        None
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct School {
+    pub fish: Vec<Fish>,
+}
+
+impl Display for School {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timers: Vec<String> = self.fish.iter().map(|fish| fish.days_until_spawn.to_string()).collect();
+        writeln!(f, "{}", timers.join(","))
+    }
+}
+
+impl FromStr for School {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        //let fish = data.split(',').map(|s| Fish { days_until_spawn: i8::from_str_radix(&s, 10).unwrap() }).collect();
+        let mut fish = Vec::new();
+        for line in parsing::lines(s).filter(|l| !l.is_blank()) {
+            for token in line.split(",") {
+                let days_until_spawn = token.parse::<i8>()?;
+                if !(0..INIT_SPAWN_DAYS).contains(&days_until_spawn) {
+                    return Err(token.error(format!("timers must be between 0 and {}", INIT_SPAWN_DAYS - 1)));
+                }
+                fish.push(Fish { days_until_spawn });
+            }
+        }
+        Ok(School { fish })
+    }
+}
+
+impl School {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// Reads the whole input from any reader, such as stdin
+    pub fn from_reader<R: Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        Ok(Self::from_str(&data)?)
+    }
+
+    pub fn tick(&mut self, days: usize) {
+        for _ in 0..days {
+            let mut babies = Vec::new();
+            for fish in self.fish.iter_mut() {
+                if let Some(mut baby) = fish.tick() {
+                    baby.tick();
+                    babies.push(baby);
+                }
+            }
+            self.fish.extend(babies);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::Read;
+use std::str::FromStr;
+use parsing::ParseError;
+//use rayon::prelude::*;
+
+const INIT_SPAWN_DAYS: usize = 9;
+const SPAWN_DAYS: usize = 7;
+
+/// Instead of holding each individual fish as an element this vec holds the population counts for
+/// each spawn day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct School {
+    pub fish: Vec<usize>,
+}
+
+impl Display for School {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timers: Vec<String> = self.fish.iter().enumerate()
+            .flat_map(|(timer, count)| std::iter::repeat_n(timer.to_string(), *count))
+            .collect();
+        writeln!(f, "{}", timers.join(","))
+    }
+}
+
+impl FromStr for School {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        //let fish = data.split(',').map(|s| Fish { days_until_spawn: i8::from_str_radix(&s, 10).unwrap() }).collect();
+        let mut fish = vec![0; INIT_SPAWN_DAYS];
+        for line in parsing::lines(s).filter(|l| !l.is_blank()) {
+            for token in line.split(",") {
+                let days_until_spawn = token.parse::<usize>()?;
+                if days_until_spawn >= INIT_SPAWN_DAYS {
+                    return Err(token.error(format!("timers must be between 0 and {}", INIT_SPAWN_DAYS - 1)));
+                }
+                fish[days_until_spawn] += 1;
+            }
+        }
+        Ok(School { fish })
+    }
+}
+
+impl School {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// Reads the whole input from any reader, such as stdin
+    pub fn from_reader<R: Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        Ok(Self::from_str(&data)?)
+    }
+
+    /// Moves the school on `days` days, or fails once there are too many fish in a group to count
+    pub fn tick(&mut self, days: usize) -> anyhow::Result<()> {
+        for _ in 0..days {
+            let spawning = self.fish[0];
+            // every group's timer goes down a day, and the spawning fish start a new group at the top
+            let mut fish = self.fish[1..].to_vec();
+            fish.push(spawning);
+            fish[SPAWN_DAYS - 1] = fish[SPAWN_DAYS - 1].checked_add(spawning).ok_or_else(too_many)?;
+            self.fish = fish;
+        }
+        Ok(())
+    }
+
+    /// How many fish there are, or an error if that's too many to count
+    pub fn count(&self) -> anyhow::Result<usize> {
+        self.fish.iter().try_fold(0usize, |total, &group| total.checked_add(group)).ok_or_else(too_many)
+    }
+}
+
+fn too_many() -> anyhow::Error {
+    anyhow::anyhow!("there are more than {} fish to count", usize::MAX)
+}
@@ -0,0 +1,29 @@
+use std::str::FromStr;
+use proptest::prelude::*;
+use day6_2::School;
+
+fn school() -> impl Strategy<Value = School> {
+    prop::collection::vec(0..20usize, 9).prop_map(|fish| School { fish })
+}
+
+proptest! {
+    #[test]
+    fn parsing_never_panics(s in "\\PC*") {
+        let _ = School::from_str(&s);
+    }
+
+    #[test]
+    fn parsing_school_like_lines_never_panics(s in "([-+]?[0-9]{0,4},?){0,20}\n?") {
+        let _ = School::from_str(&s);
+    }
+
+    #[test]
+    fn reading_arbitrary_bytes_never_panics(bytes in any::<Vec<u8>>()) {
+        let _ = School::from_reader(&bytes[..]);
+    }
+
+    #[test]
+    fn school_round_trips(school in school()) {
+        prop_assert_eq!(School::from_str(&school.to_string()).unwrap(), school);
+    }
+}
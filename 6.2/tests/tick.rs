@@ -0,0 +1,19 @@
+use std::str::FromStr;
+use day6_2::School;
+
+#[test]
+fn counts_the_sample() {
+    let mut school = School::from_str("3,4,3,1,2\n").unwrap();
+    school.tick(18).unwrap();
+    assert_eq!(school.count().unwrap(), 26);
+    school.tick(256 - 18).unwrap();
+    assert_eq!(school.count().unwrap(), 26984457539);
+}
+
+#[test]
+fn too_many_fish_is_an_error() {
+    let mut school = School::from_str("3,4,3,1,2\n").unwrap();
+    assert!(school.tick(1000).is_err());
+    let school = School { fish: vec![usize::MAX, 1, 0, 0, 0, 0, 0, 0, 0] };
+    assert!(school.count().is_err());
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Result, anyhow};
+use toml::Value;
+
+/// Expected answers keyed by puzzle name (`5.2`) and then by input name (`input`, `sample`).
+///
+/// The file uses one table per puzzle, which TOML reads as a table per day holding a table per
+/// part:
+///
+/// ```toml
+/// [5.2]
+/// input = 24164
+/// sample = 12
+/// ```
+#[derive(Default)]
+pub struct Answers {
+    puzzles: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        crate::root().join("answers.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let table: toml::Table = fs::read_to_string(path)?.parse()?;
+        let mut puzzles = BTreeMap::new();
+        for (day, parts) in table {
+            let parts = parts.as_table().ok_or_else(|| anyhow!("day {} must be a table", day))?;
+            for (part, inputs) in parts {
+                let inputs = inputs.as_table().ok_or_else(|| anyhow!("puzzle {}.{} must be a table", day, part))?;
+                let answers = inputs.iter().map(|(input, value)| (input.clone(), value_to_answer(value))).collect();
+                puzzles.insert(format!("{}.{}", day, part), answers);
+            }
+        }
+        Ok(Answers { puzzles })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut output = String::from("# Expected answers for each puzzle, checked by `advent verify`\n");
+        for (puzzle, answers) in &self.puzzles {
+            write!(output, "\n[{}]\n", puzzle)?;
+            for (input, answer) in answers {
+                writeln!(output, "{} = {}", input, answer_to_value(answer))?;
+            }
+        }
+        fs::write(path, output)?;
+        Ok(())
+    }
+
+    pub fn expected(&self, puzzle: &str, input: &str) -> Option<&str> {
+        self.puzzles.get(puzzle)?.get(input).map(String::as_str)
+    }
+
+    pub fn record(&mut self, puzzle: &str, input: &str, answer: &str) {
+        self.puzzles.entry(puzzle.to_string()).or_default().insert(input.to_string(), answer.to_string());
+    }
+}
+
+fn value_to_answer(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn answer_to_value(answer: &str) -> Value {
+    match answer.parse::<i64>() {
+        Ok(i) => Value::Integer(i),
+        Err(_) => Value::String(answer.to_string()),
+    }
+}
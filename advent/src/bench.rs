@@ -0,0 +1,78 @@
+use std::fmt::{self, Display};
+use std::time::Duration;
+use anyhow::{ensure, Result};
+use crate::days::Day;
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Nearest-rank statistics over the samples, which must not be empty
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Stats { min: samples[0], median: rank(50), p95: rank(95) }
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub input: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [("parse", &self.parse), ("part1", &self.part1), ("part2", &self.part2)]
+    }
+
+    pub const CSV_HEADER: &'static str = "day,input,phase,runs,min_us,median_us,p95_us";
+
+    pub fn csv_rows(&self) -> Vec<String> {
+        self.phases().iter().map(|(phase, stats)| {
+            format!(
+                "{},{},{},{},{},{},{}",
+                self.day, self.input, phase, self.runs,
+                stats.min.as_micros(), stats.median.as_micros(), stats.p95.as_micros(),
+            )
+        }).collect()
+    }
+}
+
+impl Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "day {} ({}, {} runs)", self.day, self.input, self.runs)?;
+        for (phase, stats) in self.phases() {
+            writeln!(f, "  {:<6} min {:>10.3?}  median {:>10.3?}  p95 {:>10.3?}", phase, stats.min, stats.median, stats.p95)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs both parts of a day `runs` times, timing parsing and each part separately
+pub fn bench(day: &Day, input_name: &str, input: &str, runs: usize) -> Result<DayBench> {
+    ensure!(runs > 0, "a benchmark needs at least one run");
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let times = (day.time)(input)?;
+        parse.push(times.parse);
+        part1.push(times.part1);
+        part2.push(times.part2);
+    }
+    Ok(DayBench {
+        day: day.number,
+        input: input_name.to_string(),
+        runs,
+        parse: Stats::new(parse),
+        part1: Stats::new(part1),
+        part2: Stats::new(part2),
+    })
+}
@@ -0,0 +1,74 @@
+use std::fmt::{self, Display};
+use anyhow::Result;
+use serde::Serialize;
+use submarine::{model, Aim, Command, Direct, State, SteeringModel, Sub};
+use crate::solver::{self, Options, Solver};
+
+pub struct Day2;
+
+pub struct Commands {
+    commands: Vec<Command>,
+    /// Overrides the steering model of both parts
+    model: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Position {
+    pub model: String,
+    pub x: isize,
+    pub depth: isize,
+    pub aim: isize,
+    pub commands: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", State::new(self.x, self.depth, self.aim).agg())
+    }
+}
+
+impl Commands {
+    fn run(&self, default: Box<dyn SteeringModel>) -> Result<Position> {
+        let model = match &self.model {
+            Some(name) => model::find(name)?,
+            None => default,
+        };
+        let mut sub = Sub::new(model);
+        sub.run_commands(&self.commands)?;
+        let state = sub.state;
+        Ok(Position {
+            model: sub.model().name().to_string(),
+            x: state.x,
+            depth: state.depth,
+            aim: state.aim,
+            commands: self.commands.len(),
+        })
+    }
+}
+
+impl Solver for Day2 {
+    type Input = Commands;
+    type Part1 = Position;
+    type Part2 = Position;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Commands { commands: Command::from_lines(input)?, model: None })
+    }
+
+    fn configure(input: &mut Self::Input, options: &Options) -> Result<()> {
+        solver::reject_days(options)?;
+        if let Some(name) = &options.model {
+            model::find(name)?;
+        }
+        input.model = options.model.clone();
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Position> {
+        input.run(Box::new(Direct))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Position> {
+        input.run(Box::new(Aim))
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use anyhow::{Result, anyhow};
+use bitword::BitWord;
+use serde::Serialize;
+use crate::solver::Solver;
+
+pub struct Day3;
+
+#[derive(Serialize)]
+pub struct Power {
+    pub gamma: usize,
+    pub epsilon: usize,
+}
+
+impl Display for Power {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.gamma as u128 * self.epsilon as u128)
+    }
+}
+
+#[derive(Serialize)]
+pub struct LifeSupport {
+    pub o2: usize,
+    pub co2: usize,
+}
+
+impl Display for LifeSupport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.o2 as u128 * self.co2 as u128)
+    }
+}
+
+/// A reading as a number, for the answers, which only fit up to 64 bits
+fn number(word: &BitWord) -> Result<usize> {
+    Ok(word.to_u64().ok_or_else(|| anyhow!("{} bits is too wide for a number", word.width()))? as usize)
+}
+
+impl Solver for Day3 {
+    type Input = day3_1::DiagReport;
+    type Part1 = Power;
+    type Part2 = LifeSupport;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day3_1::DiagReport::from_str(input)?)
+    }
+
+    fn part1(report: &Self::Input) -> Result<Power> {
+        let (gamma, epsilon) = day3_1::calc_gamma_epsilon_columns(&day3_1::Columns::from(report));
+        Ok(Power { gamma: number(&gamma)?, epsilon: number(&epsilon)? })
+    }
+
+    fn part2(report: &Self::Input) -> Result<LifeSupport> {
+        let o2 = number(day3_2::o2_scrubber_rating(report)?)?;
+        let co2 = number(day3_2::co2_scrubber_rating(report)?)?;
+        Ok(LifeSupport { o2, co2 })
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use crate::solver::Solver;
+
+pub struct Day4;
+
+#[derive(Serialize)]
+pub struct Bingo {
+    pub board: usize,
+    pub calls: usize,
+    pub last_call: u8,
+    pub score: usize,
+}
+
+impl Display for Bingo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.score)
+    }
+}
+
+impl Solver for Day4 {
+    type Input = (day4_1::Game, day4_2::Game);
+    type Part1 = Bingo;
+    type Part2 = Bingo;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((day4_1::Game::from_str(input)?, day4_2::Game::from_str(input)?))
+    }
+
+    fn part1((game, _): &Self::Input) -> Result<Bingo> {
+        let mut game = game.clone();
+        let boards = game.boards.clone();
+        let (calls, board) = game.play().ok_or_else(|| anyhow!("no board won"))?;
+        Ok(Bingo {
+            board: boards.iter().position(|b| b == board).unwrap_or_default(),
+            calls: calls.len(),
+            last_call: calls[calls.len() - 1],
+            score: day4_1::score(calls, board),
+        })
+    }
+
+    fn part2((_, game): &Self::Input) -> Result<Bingo> {
+        let mut game = game.clone();
+        let boards = game.boards.clone();
+        let win = game.play_last().ok_or_else(|| anyhow!("the last board never won"))?;
+        Ok(Bingo {
+            board: boards.iter().position(|b| b == win.board).unwrap_or_default(),
+            calls: win.calls.len(),
+            last_call: *win.winning_number,
+            score: win.score,
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use anyhow::Result;
+use serde::Serialize;
+use crate::solver::Solver;
+
+pub struct Day5;
+
+#[derive(Serialize)]
+pub struct Overlaps {
+    pub vents: usize,
+    pub overlaps: usize,
+}
+
+impl Display for Overlaps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.overlaps)
+    }
+}
+
+impl Solver for Day5 {
+    type Input = (day5_1::Map, day5_2::Map);
+    type Part1 = Overlaps;
+    type Part2 = Overlaps;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((day5_1::Map::from_str(input)?, day5_2::Map::from_str(input)?))
+    }
+
+    fn part1((map, _): &Self::Input) -> Result<Overlaps> {
+        Ok(Overlaps { vents: map.vents.len(), overlaps: map.overlaps()?.len() })
+    }
+
+    fn part2((_, map): &Self::Input) -> Result<Overlaps> {
+        Ok(Overlaps { vents: map.vents.len(), overlaps: map.overlaps()?.len() })
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use crate::solver::{self, Options, Solver};
+
+pub struct Day6;
+
+const PART1_DAYS: usize = 80;
+const PART2_DAYS: usize = 256;
+/// The most fish part 1 simulates one at a time, a byte each
+const NAIVE_FISH: usize = 100_000_000;
+
+pub struct Schools {
+    naive: day6_1::School,
+    grouped: day6_2::School,
+    /// Overrides the number of days simulated by both parts
+    days: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct Population {
+    pub days: usize,
+    /// The total number of fish at the start and then at the end of each day
+    pub per_day: Vec<usize>,
+}
+
+impl Display for Population {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.per_day.last().copied().unwrap_or_default())
+    }
+}
+
+impl Solver for Day6 {
+    type Input = Schools;
+    type Part1 = Population;
+    type Part2 = Population;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Schools {
+            naive: day6_1::School::from_str(input)?,
+            grouped: day6_2::School::from_str(input)?,
+            days: None,
+        })
+    }
+
+    fn configure(input: &mut Self::Input, options: &Options) -> Result<()> {
+        solver::reject_model(options)?;
+        input.days = options.days;
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Population> {
+        let days = input.days.unwrap_or(PART1_DAYS);
+        // the grouped count says up front whether the school would fit in memory
+        let mut grouped = input.grouped.clone();
+        let fish = grouped.tick(days).and_then(|_| grouped.count()).with_context(|| format!("day {}", days))?;
+        if fish > NAIVE_FISH {
+            bail!(
+                "part 1 would have {} fish to simulate one at a time after {} days, more than {}; part 2 counts them in groups",
+                fish, days, NAIVE_FISH
+            );
+        }
+        let mut school = input.naive.clone();
+        let mut per_day = vec![school.fish.len()];
+        for _ in 0..days {
+            school.tick(1);
+            per_day.push(school.fish.len());
+        }
+        Ok(Population { days, per_day })
+    }
+
+    fn part2(input: &Self::Input) -> Result<Population> {
+        let days = input.days.unwrap_or(PART2_DAYS);
+        let mut school = input.grouped.clone();
+        let mut per_day = vec![school.count()?];
+        for day in 1..=days {
+            let fish = school.tick(1).and_then(|_| school.count()).with_context(|| format!("day {}", day))?;
+            per_day.push(fish);
+        }
+        Ok(Population { days, per_day })
+    }
+}
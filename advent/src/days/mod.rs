@@ -0,0 +1,32 @@
+use anyhow::Result;
+use crate::solver::{self, Options, Part, PhaseTimes, Solution, Solver};
+
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, Part, &Options) -> Result<Solution>,
+    pub time: fn(&str) -> Result<PhaseTimes>,
+}
+
+impl Day {
+    const fn new<S: Solver>(number: u8) -> Self {
+        Day { number, solve: solver::solve::<S>, time: solver::time::<S> }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day6::Day6>(6),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,146 @@
+//! Random, valid puzzle inputs of any size, for workloads bigger than the shipped inputs.
+//!
+//! Every generator is driven by a seeded [`Rng`], so the same seed and settings always give the
+//! same input. Inputs are built as the day crates' own types and written with their `Display`
+//! impls, so they always parse.
+
+use anyhow::{bail, Result};
+use submarine::Command;
+use bitword::BitWord;
+use day3_1::DiagReport;
+use day4_2::Game;
+use day5_2::{Map, Point, Segment};
+use day6_1::{Fish, School};
+
+/// A small SplitMix64 generator, which gives the same sequence for a seed on every platform and
+/// release
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty
+    pub fn below(&mut self, n: usize) -> usize {
+        (((self.next_u64() as u128) * (n as u128)) >> 64) as usize
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Submarine commands with distances from 1 to 9, like the real input. The submarine never goes
+/// above the surface, so depth and aim stay positive under both parts' rules. The commands are
+/// made as they're taken, so courses can be longer than would fit in memory.
+pub fn commands(rng: &mut Rng, count: usize) -> impl Iterator<Item = Command> + '_ {
+    let mut depth = 0;
+    (0..count).map(move |_| {
+        let units = rng.below(9) as isize + 1;
+        match rng.below(3) {
+            0 => Command::Forward(units),
+            // surface instead of going up through it
+            2 if depth > 0 => {
+                let units = units.min(depth);
+                depth -= units;
+                Command::Up(units)
+            }
+            _ => {
+                depth += units;
+                Command::Down(units)
+            }
+        }
+    })
+}
+
+/// A diagnostic report of `count` readings, `width` bits each. Readings can be any width, but the
+/// day 3 answers are only numbers up to 64 bits.
+pub fn diag_report(rng: &mut Rng, count: usize, width: usize) -> Result<DiagReport> {
+    if width == 0 {
+        bail!("readings need at least one bit");
+    }
+    let data = (0..count).map(|_| BitWord::from_bits((0..width).map(|_| rng.bool()))).collect();
+    Ok(DiagReport { width, data })
+}
+
+/// A bingo game of `boards` square boards, `size` cells a side, whose cells are distinct numbers
+/// below `numbers`. Every number is called once, in a random order.
+pub fn bingo(rng: &mut Rng, boards: usize, size: usize, numbers: usize) -> Result<Game> {
+    if size == 0 {
+        bail!("boards need at least one cell");
+    }
+    if numbers > 256 {
+        bail!("bingo numbers must fit in a byte, so there can be at most 256 of them");
+    }
+    if numbers < size * size {
+        bail!("{} numbers can't fill a {}x{} board", numbers, size, size);
+    }
+    let mut calls: Vec<u8> = (0..numbers).map(|n| n as u8).collect();
+    let boards = (0..boards).map(|_| {
+        rng.shuffle(&mut calls);
+        calls[..size * size].chunks(size).map(|row| row.to_vec()).collect()
+    }).collect();
+    rng.shuffle(&mut calls);
+    Ok(Game { call_pointer: 0, calls, boards })
+}
+
+/// Relative weights of each vent orientation
+#[derive(Debug, Clone, Copy)]
+pub struct Mix {
+    pub horizontal: usize,
+    pub vertical: usize,
+    pub diagonal: usize,
+}
+
+/// `count` vent segments on an `extent` by `extent` floor, with orientations picked by `mix`
+pub fn vents(rng: &mut Rng, count: usize, extent: usize, mix: Mix) -> Result<Map> {
+    let total = mix.horizontal + mix.vertical + mix.diagonal;
+    if total == 0 {
+        bail!("at least one vent orientation needs a weight");
+    }
+    if extent == 0 {
+        bail!("the ocean floor needs at least one point");
+    }
+    let vents = (0..count).map(|_| {
+        let (x, y) = (rng.below(extent), rng.below(extent));
+        let pick = rng.below(total);
+        let end = if pick < mix.horizontal {
+            Point(rng.below(extent), y)
+        } else if pick < mix.horizontal + mix.vertical {
+            Point(x, rng.below(extent))
+        } else {
+            // keep both ends of the 45 degree line on the floor
+            let (right, down) = (rng.bool(), rng.bool());
+            let room_x = if right { extent - 1 - x } else { x };
+            let room_y = if down { extent - 1 - y } else { y };
+            let len = rng.below(room_x.min(room_y) + 1);
+            Point(if right { x + len } else { x - len }, if down { y + len } else { y - len })
+        };
+        Segment(Point(x, y), end)
+    }).collect();
+    Ok(Map { vents })
+}
+
+/// A school of `count` lanternfish with timers from 1 to 5, like the real input
+pub fn school(rng: &mut Rng, count: usize) -> School {
+    let fish = (0..count).map(|_| Fish { days_until_spawn: rng.below(5) as i8 + 1 }).collect();
+    School { fish }
+}
@@ -0,0 +1,14 @@
+use std::path::{Path, PathBuf};
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod generate;
+pub mod puzzles;
+pub mod solver;
+pub mod verify;
+
+/// The repository root, which holds a directory per puzzle and the recorded answers
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
@@ -0,0 +1,311 @@
+use std::fs;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use anyhow::{Result, anyhow};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use advent::answers::Answers;
+use advent::bench::{self, DayBench};
+use advent::days;
+use advent::generate::{self, Mix, Rng};
+use advent::puzzles::{self, Puzzle};
+use advent::solver::{Options, Part, Solution};
+use advent::verify::{self, Status};
+use submarine::binary;
+
+mod sub;
+
+/// Runs, checks and times the Advent of Code 2021 solutions
+#[derive(Parser)]
+#[command(name = "advent")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the available puzzles
+    List,
+    /// Solve a puzzle
+    Run(RunArgs),
+    /// Check every solver against the recorded answers
+    Verify {
+        /// Record new and changed answers in answers.toml
+        #[arg(long)]
+        update: bool,
+        /// Puzzles (`5.2`) or days (`5`) to check, all of them when empty
+        puzzles: Vec<String>,
+    },
+    /// Time parsing and both parts of each day
+    Bench {
+        /// How many times to run each day, at least once
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Use each day's sample.txt instead of input.txt
+        #[arg(long)]
+        sample: bool,
+        /// Print the results as CSV
+        #[arg(long)]
+        csv: bool,
+        /// Days to run, all of them when empty
+        days: Vec<String>,
+    },
+    /// Write a random, valid puzzle input
+    Generate(GenerateArgs),
+    /// Look into how the day 2 submarine moves
+    Sub(sub::SubArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// A day and part (`5.2`), or just a day (`5`) to solve both parts
+    puzzle: String,
+    /// Solve only this part of the day
+    #[arg(short, long)]
+    part: Option<Part>,
+    /// Input file, relative to the current directory or the puzzle directory; `-` reads stdin
+    #[arg(short, long, default_value = "input.txt", conflicts_with = "sample")]
+    input: String,
+    /// Use the puzzle's sample.txt instead of input.txt
+    #[arg(short, long)]
+    sample: bool,
+    /// Number of days to simulate, for puzzles that run a simulation
+    #[arg(short, long)]
+    days: Option<usize>,
+    /// Steering model for the submarine puzzles (`direct`, `aim`, `direct-3d` or `aim-3d`), instead of each part's own
+    #[arg(short, long)]
+    model: Option<String>,
+    /// Print just the answer, or a JSON object with intermediate values and timing
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    #[command(subcommand)]
+    input: Input,
+    /// Seed for the random generator, the same seed always gives the same input
+    #[arg(long, default_value_t = 0, global = true)]
+    seed: u64,
+    /// File to write the input to, instead of stdout
+    #[arg(short, long, global = true)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Input {
+    /// Submarine commands, for day 2
+    #[command(alias = "2")]
+    Commands {
+        #[arg(short = 'n', long, default_value_t = 1000)]
+        count: usize,
+        /// Write the compact binary format instead of one command per line
+        #[arg(long)]
+        binary: bool,
+    },
+    /// A diagnostic report, for day 3
+    #[command(alias = "3")]
+    Diagnostics {
+        #[arg(short = 'n', long, default_value_t = 1000)]
+        count: usize,
+        /// Bits per reading, the answers only fit up to 64
+        #[arg(short, long, default_value_t = 12)]
+        width: usize,
+    },
+    /// Bingo calls and boards, for day 4
+    #[command(alias = "4")]
+    Bingo {
+        #[arg(short = 'n', long, default_value_t = 100)]
+        boards: usize,
+        /// Cells along each side of a board
+        #[arg(short, long, default_value_t = 5)]
+        size: usize,
+        /// How many numbers there are to call, at most 256
+        #[arg(long, default_value_t = 100)]
+        numbers: usize,
+    },
+    /// Hydrothermal vent segments, for day 5
+    #[command(alias = "5")]
+    Vents {
+        #[arg(short = 'n', long, default_value_t = 500)]
+        count: usize,
+        /// Width and height of the ocean floor
+        #[arg(short, long, default_value_t = 1000)]
+        extent: usize,
+        /// Relative weight of horizontal segments
+        #[arg(long, default_value_t = 1)]
+        horizontal: usize,
+        /// Relative weight of vertical segments
+        #[arg(long, default_value_t = 1)]
+        vertical: usize,
+        /// Relative weight of diagonal segments
+        #[arg(long, default_value_t = 1)]
+        diagonal: usize,
+    },
+    /// Lanternfish timers, for day 6
+    #[command(alias = "6")]
+    Lanternfish {
+        #[arg(short = 'n', long, default_value_t = 300)]
+        count: usize,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    puzzle: String,
+    #[serde(flatten)]
+    solution: &'a Solution,
+}
+
+fn list() {
+    for puzzle in puzzles::all() {
+        println!("{}", puzzle.name());
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let selected = puzzles::select(&args.puzzle, args.part)?;
+    let path = if args.sample { "sample.txt" } else { args.input.as_str() };
+    // stdin can only be read once, so share it between parts
+    let stdin = if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Some(input)
+    } else {
+        None
+    };
+    let options = Options { days: args.days, model: args.model };
+    for puzzle in &selected {
+        let input = match &stdin {
+            Some(input) => input.clone(),
+            None => puzzle.read_input(path)?,
+        };
+        let solution = puzzle.solve(&input, &options)?;
+        match args.format {
+            Format::Text if selected.len() == 1 => println!("{}", solution.answer),
+            Format::Text => println!("{}: {}", puzzle.name(), solution.answer),
+            Format::Json => println!("{}", serde_json::to_string(&Report { puzzle: puzzle.name(), solution: &solution })?),
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether every check passed
+fn verify(update: bool, names: &[String]) -> Result<bool> {
+    let selected = if names.is_empty() {
+        puzzles::all().collect()
+    } else {
+        let mut selected = Vec::new();
+        for name in names {
+            selected.extend(puzzles::select(name, None)?);
+        }
+        selected
+    };
+    let path = Answers::default_path();
+    let mut answers = Answers::load(&path)?;
+    let checks = verify::verify(&answers, selected);
+    for check in &checks {
+        println!("{}", check);
+    }
+    let failed = checks.iter().filter(|c| !c.status.is_ok()).count();
+    println!("{} checked, {} failed", checks.len(), failed);
+    if update {
+        for check in &checks {
+            match &check.status {
+                Status::New(actual) | Status::Regression { actual, .. } => answers.record(&check.puzzle, check.input, actual),
+                Status::Pass | Status::Fail(_) => (),
+            }
+        }
+        answers.save(&path)?;
+        println!("answers written to {}", path.display());
+    }
+    Ok(failed == 0)
+}
+
+fn bench(runs: u64, sample: bool, csv: bool, names: &[String]) -> Result<()> {
+    let selected = if names.is_empty() {
+        days::DAYS.iter().collect()
+    } else {
+        names.iter().map(|d| puzzles::find_day(d)).collect::<Result<Vec<_>>>()?
+    };
+    let input = if sample { "sample" } else { "input" };
+    if csv {
+        println!("{}", DayBench::CSV_HEADER);
+    }
+    for day in selected {
+        // both parts of a day share an input, so read it from the part one directory
+        let path = Puzzle { day, part: Part::One }.dir().join(format!("{}.txt", input));
+        let data = fs::read_to_string(&path).map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+        let result = bench::bench(day, input, &data, usize::try_from(runs)?)?;
+        if csv {
+            for row in result.csv_rows() {
+                println!("{}", row);
+            }
+        } else {
+            print!("{}", result);
+        }
+    }
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<()> {
+    let mut rng = Rng::new(args.seed);
+    let out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(fs::File::create(path).map_err(|e| anyhow!("{}: {}", path.display(), e))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut out = BufWriter::new(out);
+    match args.input {
+        // written as they're made, so courses can be bigger than memory
+        Input::Commands { count, binary: true } => {
+            let mut encoder = binary::Encoder::new(&mut out)?;
+            for command in generate::commands(&mut rng, count) {
+                encoder.write(command)?;
+            }
+        }
+        Input::Commands { count, binary: false } => {
+            for command in generate::commands(&mut rng, count) {
+                writeln!(out, "{}", command)?;
+            }
+        }
+        Input::Diagnostics { count, width } => write!(out, "{}", generate::diag_report(&mut rng, count, width)?)?,
+        Input::Bingo { boards, size, numbers } => write!(out, "{}", generate::bingo(&mut rng, boards, size, numbers)?)?,
+        Input::Vents { count, extent, horizontal, vertical, diagonal } => {
+            write!(out, "{}", generate::vents(&mut rng, count, extent, Mix { horizontal, vertical, diagonal })?)?
+        }
+        Input::Lanternfish { count } => write!(out, "{}", generate::school(&mut rng, count))?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::List => {
+            list();
+            Ok(true)
+        }
+        Command::Run(args) => run(args).map(|_| true),
+        Command::Verify { update, puzzles } => verify(update, &puzzles),
+        Command::Bench { runs, sample, csv, days } => bench(runs, sample, csv, &days).map(|_| true),
+        Command::Generate(args) => generate(args).map(|_| true),
+        Command::Sub(args) => sub::run(args).map(|_| true),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use anyhow::{Result, anyhow, bail};
+use crate::days::{self, Day};
+use crate::solver::{Options, Part, Solution};
+
+/// One part of one day, named `<day>.<part>` after its directory in the repository
+pub struct Puzzle {
+    pub day: &'static Day,
+    pub part: Part,
+}
+
+pub fn all() -> impl Iterator<Item = Puzzle> {
+    days::DAYS.iter().flat_map(|day| Part::ALL.into_iter().map(move |part| Puzzle { day, part }))
+}
+
+pub fn find(name: &str) -> Result<Puzzle> {
+    let (day, part) = name.split_once('.').ok_or_else(|| anyhow!("puzzle names look like 5.2, got: {}", name))?;
+    let day = find_day(day)?;
+    let part = part.parse::<Part>()?;
+    Ok(Puzzle { day, part })
+}
+
+pub fn find_day(day: &str) -> Result<&'static Day> {
+    day.parse::<u8>().ok().and_then(days::find).ok_or_else(|| anyhow!("unknown day: {}", day))
+}
+
+/// Selects puzzles by name (`5.2`) or by day (`5`), optionally narrowed to one part
+pub fn select(name: &str, part: Option<Part>) -> Result<Vec<Puzzle>> {
+    if name.contains('.') {
+        let puzzle = find(name)?;
+        match part {
+            Some(part) if part != puzzle.part => bail!("puzzle {} is not part {}", name, part),
+            _ => Ok(vec![puzzle]),
+        }
+    } else {
+        let day = find_day(name)?;
+        let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+        Ok(parts.into_iter().map(|part| Puzzle { day, part }).collect())
+    }
+}
+
+impl Puzzle {
+    pub fn name(&self) -> String {
+        format!("{}.{}", self.day.number, self.part)
+    }
+
+    pub fn solve(&self, input: &str, options: &Options) -> Result<Solution> {
+        (self.day.solve)(input, self.part, options)
+    }
+
+    /// The puzzle's directory in the repository, where its input files live
+    pub fn dir(&self) -> PathBuf {
+        crate::root().join(self.name())
+    }
+
+    /// Resolves an input path, falling back to the puzzle's own directory when the path does not
+    /// exist relative to the current directory
+    pub fn input_path(&self, path: &str) -> PathBuf {
+        let given = PathBuf::from(path);
+        if given.exists() || given.is_absolute() {
+            given
+        } else {
+            self.dir().join(given)
+        }
+    }
+
+    /// Reads the input at `path`, or stdin when the path is `-`
+    pub fn read_input(&self, path: &str) -> Result<String> {
+        if path == "-" {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            return Ok(input);
+        }
+        let path = self.input_path(path);
+        fs::read_to_string(&path).map_err(|e| anyhow!("{}: {}", path.display(), e))
+    }
+}
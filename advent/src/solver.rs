@@ -0,0 +1,150 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use anyhow::{Result, anyhow, bail};
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("invalid part: {}", s)),
+        }
+    }
+}
+
+/// Settings from the command line that only some days understand
+#[derive(Debug, Default, Clone)]
+pub struct Options {
+    /// How many days to simulate instead of the puzzle's own count
+    pub days: Option<usize>,
+    /// The steering model to drive the submarine with instead of each part's own
+    pub model: Option<String>,
+}
+
+/// A single day's puzzle. The input is parsed once and shared by both parts.
+///
+/// Answers display as the value the puzzle asks for and serialize with whatever intermediate
+/// values went into it.
+pub trait Solver {
+    type Input;
+    type Part1: Display + Serialize;
+    type Part2: Display + Serialize;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Applies command line options to the parsed input. Days that take no options reject them.
+    fn configure(_input: &mut Self::Input, options: &Options) -> Result<()> {
+        reject_days(options)?;
+        reject_model(options)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+pub fn reject_days(options: &Options) -> Result<()> {
+    if options.days.is_some() {
+        bail!("this puzzle does not simulate a number of days");
+    }
+    Ok(())
+}
+
+pub fn reject_model(options: &Options) -> Result<()> {
+    if options.model.is_some() {
+        bail!("this puzzle has no steering model");
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+pub struct Timing {
+    #[serde(rename = "parse_us", serialize_with = "micros")]
+    pub parse: Duration,
+    #[serde(rename = "solve_us", serialize_with = "micros")]
+    pub solve: Duration,
+}
+
+fn micros<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_micros())
+}
+
+/// A solved part with its answer type erased so days can share a registry
+#[derive(Serialize)]
+pub struct Solution {
+    pub answer: String,
+    pub details: serde_json::Value,
+    pub timing: Timing,
+}
+
+fn solution<A: Display + Serialize>(answer: A, parse: Duration, solve: Duration) -> Result<Solution> {
+    Ok(Solution {
+        answer: answer.to_string(),
+        details: serde_json::to_value(&answer)?,
+        timing: Timing { parse, solve },
+    })
+}
+
+/// Wall time spent in each phase of a full run of both parts
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+pub fn time<S: Solver>(input: &str) -> Result<PhaseTimes> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    S::part1(&input)?;
+    let part1 = start.elapsed();
+    let start = Instant::now();
+    S::part2(&input)?;
+    let part2 = start.elapsed();
+    Ok(PhaseTimes { parse, part1, part2 })
+}
+
+pub fn solve<S: Solver>(input: &str, part: Part, options: &Options) -> Result<Solution> {
+    let start = Instant::now();
+    let mut input = S::parse(input)?;
+    S::configure(&mut input, options)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    match part {
+        Part::One => {
+            let answer = S::part1(&input)?;
+            solution(answer, parse, start.elapsed())
+        }
+        Part::Two => {
+            let answer = S::part2(&input)?;
+            solution(answer, parse, start.elapsed())
+        }
+    }
+}
@@ -0,0 +1,405 @@
+//! `advent sub`: tools for looking into how the day 2 submarine moves
+
+use std::fs;
+use std::io::{self, BufReader, BufWriter, IsTerminal, Read, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use anyhow::{Result, anyhow};
+use clap::{Args, Subcommand};
+use submarine::plan::{self, Limits, Target};
+use submarine::repl::{self, Session};
+use submarine::{binary, diff, model, optimize, render, Command, Edit, Constraints, Mode, Program, State, Step, Sub, Trace, Violation};
+use advent::puzzles;
+
+#[derive(Args)]
+pub struct SubArgs {
+    #[command(subcommand)]
+    command: SubCommand,
+}
+
+#[derive(Subcommand)]
+enum SubCommand {
+    /// Follow a program, or a course in the binary format, as it's read, for courses too big for
+    /// memory
+    Run {
+        #[command(flatten)]
+        dive: Dive,
+        /// Read the whole course and combine the commands' effects in parallel; skips constraints
+        #[arg(long)]
+        parallel: bool,
+    },
+    /// Record the state after every command and summarize the dive
+    Trace {
+        #[command(flatten)]
+        dive: Dive,
+        /// Print every step as CSV instead of a summary
+        #[arg(long)]
+        csv: bool,
+        /// Also report the first step that reaches this depth
+        #[arg(long)]
+        crossing: Option<isize>,
+    },
+    /// Work out a short course to a position, written one command per line
+    Plan {
+        /// Horizontal position to reach
+        #[arg(long, allow_negative_numbers = true)]
+        x: isize,
+        /// Depth to reach
+        #[arg(long, allow_negative_numbers = true)]
+        depth: isize,
+        /// Steering model to plan for
+        #[arg(short, long, default_value = "aim")]
+        model: String,
+        /// The largest distance any one command may carry
+        #[arg(long)]
+        max_magnitude: Option<isize>,
+        /// The most commands the course may have
+        #[arg(long)]
+        max_commands: Option<usize>,
+    },
+    /// Line up two courses, show where they part ways and how far apart they end
+    Diff {
+        /// The first course, one command per line, relative to the current directory or the day 2
+        /// directory
+        left: String,
+        /// The course to compare it with
+        right: String,
+        /// Steering model to follow both courses with
+        #[arg(short, long, default_value = "aim")]
+        model: String,
+        /// Unchanged commands to show around each change
+        #[arg(short = 'U', long, default_value_t = 2)]
+        context: usize,
+    },
+    /// Merge and cancel commands, writing a shorter course that ends in the same place
+    Optimize {
+        #[command(flatten)]
+        dive: Dive,
+    },
+    /// Convert a course of one command per line to the binary format, or back
+    Convert {
+        /// Course to convert, relative to the current directory or the day 2 directory; `-` reads
+        /// stdin
+        input: String,
+        /// File to write the converted course to, instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Drive the submarine a command at a time; `help` lists what else it understands
+    Repl {
+        #[command(flatten)]
+        steering: Steering,
+    },
+    /// Plot depth against horizontal position
+    Plot {
+        #[command(flatten)]
+        dive: Dive,
+        /// Width of the plot, in characters or pixels for SVG
+        #[arg(long)]
+        width: Option<usize>,
+        /// Height of the plot, in lines or pixels for SVG
+        #[arg(long)]
+        height: Option<usize>,
+        /// Write an SVG image to this file instead of plotting in the terminal
+        #[arg(long)]
+        svg: Option<PathBuf>,
+    },
+}
+
+/// The commands to follow and how to follow them
+#[derive(Args)]
+struct Dive {
+    /// Commands or program file, relative to the current directory or the day 2 directory; `-` reads stdin
+    #[arg(short, long, default_value = "input.txt", conflicts_with = "sample")]
+    input: String,
+    /// Use the day's sample.txt instead of input.txt
+    #[arg(short, long)]
+    sample: bool,
+    #[command(flatten)]
+    steering: Steering,
+}
+
+/// How the submarine steers and the limits it keeps to
+#[derive(Args)]
+struct Steering {
+    /// Steering model to drive the submarine with
+    #[arg(short, long, default_value = "aim")]
+    model: String,
+    /// The shallowest the submarine may go, 0 keeps it in the water
+    #[arg(long, allow_negative_numbers = true)]
+    min_depth: Option<isize>,
+    /// The deepest the submarine may go
+    #[arg(long, allow_negative_numbers = true)]
+    max_depth: Option<isize>,
+    /// The steepest aim allowed, up or down
+    #[arg(long)]
+    max_aim: Option<isize>,
+    /// What to do when a command breaks a limit or overflows: clamp, error or warn
+    #[arg(long, default_value = "warn")]
+    on_violation: Mode,
+}
+
+impl Dive {
+    fn path(&self) -> &str {
+        if self.sample { "sample.txt" } else { self.input.as_str() }
+    }
+
+    /// Reads the commands as they're needed, from a program or the binary format
+    fn commands(&self) -> Result<Box<dyn Iterator<Item = Result<Command>>>> {
+        let mut reader = BufReader::new(open(self.path())?);
+        if binary::is_binary(&mut reader)? {
+            Ok(Box::new(binary::Decoder::new(reader)?))
+        } else {
+            Ok(Box::new(Program::stream(reader)))
+        }
+    }
+
+    /// Reads the commands, which may use the whole extended language
+    fn program(&self) -> Result<Program> {
+        let input = puzzles::find("2.1")?.read_input(self.path())?;
+        Ok(Program::from_str(&input)?)
+    }
+
+    fn sub(&self) -> Result<Sub> {
+        self.steering.sub()
+    }
+
+    /// Follows every command, recording the way and the limits broken on it
+    fn trace(&self) -> Result<(Trace, Vec<Violation>)> {
+        let program = self.program()?;
+        let mut sub = self.sub()?;
+        sub.record();
+        let violations = sub.run_program(&program)?;
+        Ok((sub.take_trace().unwrap_or_default(), violations))
+    }
+}
+
+impl Steering {
+    fn sub(&self) -> Result<Sub> {
+        let model = model::find(&self.model)?;
+        let constraints = Constraints {
+            min_depth: self.min_depth,
+            max_depth: self.max_depth,
+            max_aim: self.max_aim,
+            mode: self.on_violation,
+        };
+        Ok(Sub::new(model).with_constraints(constraints))
+    }
+}
+
+/// Opens a course for streaming, relative to the current directory or the day 2 directory, or
+/// stdin for `-`
+fn open(path: &str) -> Result<Box<dyn Read>> {
+    if path == "-" {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let path = puzzles::find("2.1")?.input_path(path);
+    let file = fs::File::open(&path).map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+    Ok(Box::new(file))
+}
+
+/// Reports violations on stderr, out of the way of the output
+fn warn(violations: &[Violation]) {
+    for violation in violations {
+        eprintln!("warning: {}", violation);
+    }
+}
+
+fn describe(step: Option<&Step>) -> String {
+    match step {
+        Some(step) => format!("step {} ({}), depth {}", step.index, step.command, step.state.depth),
+        None => "never".to_string(),
+    }
+}
+
+fn run_stream(dive: &Dive, parallel: bool) -> Result<()> {
+    let mut sub = dive.sub()?;
+    let mut violations = 0;
+    let followed = if parallel {
+        let commands = dive.commands()?.collect::<Result<Vec<_>>>()?;
+        sub.run_parallel(&commands).ok_or_else(|| {
+            anyhow!("the {} model has no effects to combine, or the position overflowed", dive.steering.model)
+        })?;
+        commands.len()
+    } else {
+        sub.run_stream(dive.commands()?, |violation| {
+            violations += 1;
+            warn(&[violation]);
+        })?
+    };
+    let state = sub.state;
+    println!("commands    {}", followed);
+    println!("final       {}", state);
+    println!("violations  {}", violations);
+    Ok(())
+}
+
+fn trace(dive: &Dive, csv: bool, crossing: Option<isize>) -> Result<()> {
+    let (trace, violations) = dive.trace()?;
+    if csv {
+        warn(&violations);
+        return Ok(trace.write_csv(io::stdout().lock())?);
+    }
+    let end = trace.states().last().unwrap_or_default();
+    println!("steps       {}", trace.steps.len());
+    println!("final       {}", end);
+    println!("max depth   {}", describe(trace.max_depth()));
+    if let Some(depth) = crossing {
+        println!("reached {}  {}", depth, describe(trace.first_crossing(depth)));
+    }
+    println!("at surface  {} steps", trace.time_at_surface());
+    println!("violations  {}", violations.len());
+    for violation in &violations {
+        println!("  {}", violation);
+    }
+    Ok(())
+}
+
+fn plan(model: &str, target: Target, limits: &Limits) -> Result<()> {
+    for command in plan::plan(model::find(model)?, target, limits)? {
+        println!("{}", command);
+    }
+    Ok(())
+}
+
+fn read_course(path: &str) -> Result<Vec<Command>> {
+    let path = puzzles::find("2.1")?.input_path(path);
+    Command::from_file(&path.to_string_lossy()).map_err(|e| anyhow!("{}: {}", path.display(), e))
+}
+
+fn show_state(state: State) -> String {
+    format!("{}, agg {}", state, state.agg())
+}
+
+/// Prints the changed commands with `context` unchanged ones around them, numbered by line in
+/// each course
+fn show_edits(edits: &[Edit], context: usize) {
+    let changed: Vec<usize> =
+        edits.iter().enumerate().filter(|(_, e)| !matches!(e, Edit::Same(_))).map(|(i, _)| i).collect();
+    let near = |i: usize| {
+        let next = changed.partition_point(|&c| c < i);
+        let after = changed.get(next).is_some_and(|&c| c - i <= context);
+        let before = next > 0 && i - changed[next - 1] <= context;
+        after || before
+    };
+    let (mut left, mut right) = (0, 0);
+    let mut skipped = false;
+    for (i, edit) in edits.iter().enumerate() {
+        let (l, r) = match edit {
+            Edit::Same(_) => (Some(left + 1), Some(right + 1)),
+            Edit::Removed(_) => (Some(left + 1), None),
+            Edit::Added(_) => (None, Some(right + 1)),
+        };
+        left += l.is_some() as usize;
+        right += r.is_some() as usize;
+        if !near(i) {
+            skipped = true;
+            continue;
+        }
+        if skipped {
+            println!("  ...");
+            skipped = false;
+        }
+        let line = |n: Option<usize>| n.map_or(String::new(), |n| n.to_string());
+        println!("{:>6} {:>6}  {}", line(l), line(r), edit);
+    }
+    if skipped {
+        println!("  ...");
+    }
+}
+
+fn diff(left: &str, right: &str, model: &str, context: usize) -> Result<()> {
+    let (left, right) = (read_course(left)?, read_course(right)?);
+    let (mut left_sub, mut right_sub) = (Sub::new(model::find(model)?), Sub::new(model::find(model)?));
+    let comparison = diff::compare(&mut left_sub, &left, &mut right_sub, &right)?;
+    let Some(divergence) = comparison.divergence else {
+        println!("the courses are the same, {} commands", left.len());
+        println!("final       {}", show_state(comparison.left));
+        return Ok(());
+    };
+    let command = |c: Option<Command>| c.map_or("the end".to_string(), |c| c.to_string());
+    println!(
+        "diverges    after {} commands: {} / {}",
+        divergence.index,
+        command(divergence.left),
+        command(divergence.right)
+    );
+    println!("from        {}", show_state(divergence.state));
+    println!("left        {}", show_state(comparison.left));
+    println!("right       {}", show_state(comparison.right));
+    let [x, depth, aim, agg] = comparison.change();
+    println!("change      x {:+}, depth {:+}, aim {:+}, agg {:+}", x, depth, aim, agg);
+    println!();
+    show_edits(&comparison.edits, context);
+    Ok(())
+}
+
+fn optimize(dive: &Dive) -> Result<()> {
+    let course = dive.commands()?.collect::<Result<Vec<_>>>()?;
+    let model = model::find(&dive.steering.model)?;
+    let optimized = optimize::optimize(model.as_ref(), &course);
+    let mut out = BufWriter::new(io::stdout().lock());
+    for command in &optimized {
+        writeln!(out, "{}", command)?;
+    }
+    out.flush()?;
+    eprintln!("{} commands down to {}", course.len(), optimized.len());
+    Ok(())
+}
+
+fn convert(input: &str, output: Option<PathBuf>) -> Result<()> {
+    let mut reader = BufReader::new(open(input)?);
+    let to_binary = !binary::is_binary(&mut reader)?;
+    let out: Box<dyn Write> = match &output {
+        Some(path) => Box::new(fs::File::create(path).map_err(|e| anyhow!("{}: {}", path.display(), e))?),
+        None if to_binary && io::stdout().is_terminal() => {
+            return Err(anyhow!("not writing a binary course to the terminal, pass --output"));
+        }
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut out = BufWriter::new(out);
+    if to_binary {
+        let mut encoder = binary::Encoder::new(out)?;
+        for command in Command::stream(reader) {
+            encoder.write(command?)?;
+        }
+        encoder.finish()?;
+    } else {
+        for command in binary::Decoder::new(reader)? {
+            writeln!(out, "{}", command?)?;
+        }
+        out.flush()?;
+    }
+    Ok(())
+}
+
+fn plot(dive: &Dive, width: Option<usize>, height: Option<usize>, svg: Option<PathBuf>) -> Result<()> {
+    let (trace, violations) = dive.trace()?;
+    warn(&violations);
+    match svg {
+        Some(path) => {
+            let image = render::svg(&trace, width.unwrap_or(800), height.unwrap_or(500));
+            fs::write(&path, image).map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+        }
+        None => print!("{}", render::ascii(&trace, width.unwrap_or(72), height.unwrap_or(20))),
+    }
+    Ok(())
+}
+
+pub fn run(args: SubArgs) -> Result<()> {
+    match args.command {
+        SubCommand::Run { dive, parallel } => run_stream(&dive, parallel),
+        SubCommand::Trace { dive, csv, crossing } => trace(&dive, csv, crossing),
+        SubCommand::Plan { x, depth, model, max_magnitude, max_commands } => {
+            plan(&model, Target { x, depth }, &Limits { max_magnitude, max_commands })
+        }
+        SubCommand::Diff { left, right, model, context } => diff(&left, &right, &model, context),
+        SubCommand::Optimize { dive } => optimize(&dive),
+        SubCommand::Convert { input, output } => convert(&input, output),
+        SubCommand::Repl { steering } => {
+            let mut session = Session::new(steering.sub()?);
+            Ok(repl::run(&mut session, io::stdin().lock(), io::stdout().lock())?)
+        }
+        SubCommand::Plot { dive, width, height, svg } => plot(&dive, width, height, svg),
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::{self, Display};
+use std::fs;
+use crate::answers::Answers;
+use crate::puzzles::Puzzle;
+use crate::solver::Options;
+
+/// Inputs checked for every puzzle, read from `<name>.txt` in the puzzle directory
+pub const INPUTS: &[&str] = &["input", "sample"];
+
+pub enum Status {
+    Pass,
+    /// The solver errored or the input could not be read
+    Fail(String),
+    /// The solver produced a different answer than the one recorded
+    Regression { expected: String, actual: String },
+    /// There is no recorded answer to compare against
+    New(String),
+}
+
+pub struct Check {
+    pub puzzle: String,
+    pub input: &'static str,
+    pub status: Status,
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Status::Pass | Status::New(_))
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:<6} ", self.puzzle, self.input)?;
+        match &self.status {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(err) => write!(f, "FAIL {}", err),
+            Status::Regression { expected, actual } => write!(f, "REGRESSION expected {} got {}", expected, actual),
+            Status::New(actual) => write!(f, "new {}", actual),
+        }
+    }
+}
+
+fn check(answers: &Answers, puzzle: &Puzzle, input: &'static str) -> Option<Check> {
+    let name = puzzle.name();
+    let expected = answers.expected(&name, input);
+    let path = puzzle.dir().join(format!("{}.txt", input));
+    // a missing input is only a failure when we have an answer recorded for it
+    if !path.exists() && expected.is_none() {
+        return None;
+    }
+    let result = fs::read_to_string(&path)
+        .map_err(anyhow::Error::from)
+        .and_then(|data| puzzle.solve(&data, &Options::default()))
+        .map(|solution| solution.answer);
+    let status = match (result, expected) {
+        (Err(err), _) => Status::Fail(format!("{:#}", err)),
+        (Ok(actual), None) => Status::New(actual),
+        (Ok(actual), Some(expected)) if actual == expected => Status::Pass,
+        (Ok(actual), Some(expected)) => Status::Regression { expected: expected.to_string(), actual },
+    };
+    Some(Check { puzzle: name, input, status })
+}
+
+/// Runs every puzzle against each of its inputs and compares the results to the recorded answers
+pub fn verify(answers: &Answers, puzzles: impl IntoIterator<Item = Puzzle>) -> Vec<Check> {
+    puzzles.into_iter()
+        .flat_map(|puzzle| INPUTS.iter().filter_map(|input| check(answers, &puzzle, input)).collect::<Vec<_>>())
+        .collect()
+}
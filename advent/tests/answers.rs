@@ -0,0 +1,15 @@
+use advent::answers::Answers;
+use advent::puzzles;
+use advent::verify::{self, Status};
+
+#[test]
+fn recorded_answers_still_hold() {
+    let answers = Answers::load(&Answers::default_path()).unwrap();
+    let checks = verify::verify(&answers, puzzles::all());
+    // unrecorded answers are fine interactively but every puzzle should have one checked in
+    let failures: Vec<String> = checks.iter()
+        .filter(|c| !matches!(c.status, Status::Pass))
+        .map(|c| c.to_string())
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,26 @@
+use advent::{bench, puzzles};
+use advent::solver::Options;
+
+fn solve(puzzle: &str, input: &str, days: usize) -> anyhow::Result<String> {
+    let options = Options { days: Some(days), ..Options::default() };
+    Ok(puzzles::find(puzzle)?.solve(input, &options)?.answer)
+}
+
+#[test]
+fn long_fish_simulations_are_errors() {
+    let sample = "3,4,3,1,2\n";
+    assert_eq!(solve("6.1", sample, 80).unwrap(), "5934");
+    // one fish at a time, 256 days would take far more memory than there is
+    assert!(solve("6.1", sample, 256).is_err());
+    assert_eq!(solve("6.2", sample, 256).unwrap(), "26984457539");
+    let err = solve("6.2", sample, 1000).unwrap_err();
+    assert!(format!("{:#}", err).contains("fish to count"), "{:#}", err);
+}
+
+#[test]
+fn benchmarks_need_a_run() {
+    let day = puzzles::find_day("3").unwrap();
+    let sample = "00100\n11110\n10110\n";
+    assert!(bench::bench(day, "sample", sample, 0).is_err());
+    assert_eq!(bench::bench(day, "sample", sample, 3).unwrap().runs, 3);
+}
@@ -0,0 +1,64 @@
+use advent::generate::{self, Mix, Rng};
+use advent::puzzles;
+use advent::solver::Options;
+
+fn solve(puzzle: &str, input: &str) -> String {
+    puzzles::find(puzzle).unwrap().solve(input, &Options::default()).unwrap().answer
+}
+
+/// Every generator's output for one seed, as it would be written
+fn generate_all(seed: u64) -> Vec<String> {
+    let mix = Mix { horizontal: 1, vertical: 1, diagonal: 1 };
+    vec![
+        generate::commands(&mut Rng::new(seed), 100).map(|c| format!("{}\n", c)).collect(),
+        generate::diag_report(&mut Rng::new(seed), 100, 12).unwrap().to_string(),
+        generate::bingo(&mut Rng::new(seed), 5, 5, 100).unwrap().to_string(),
+        generate::vents(&mut Rng::new(seed), 100, 1000, mix).unwrap().to_string(),
+        generate::school(&mut Rng::new(seed), 100).to_string(),
+    ]
+}
+
+#[test]
+fn the_same_seed_generates_the_same_inputs() {
+    for seed in [0, 1, 42, u64::MAX] {
+        assert_eq!(generate_all(seed), generate_all(seed), "seed {}", seed);
+    }
+}
+
+#[test]
+fn different_seeds_generate_different_inputs() {
+    for (a, b) in generate_all(1).iter().zip(generate_all(2)) {
+        assert_ne!(*a, b);
+    }
+}
+
+#[test]
+fn generated_diagnostics_are_solvable() {
+    // seeds 1 and 5 repeat readings, which used to leave the CO2 rating with nothing to pick
+    for seed in 0..10 {
+        let report = generate::diag_report(&mut Rng::new(seed), 1000, 12).unwrap().to_string();
+        solve("3.1", &report);
+        solve("3.2", &report);
+    }
+}
+
+#[test]
+fn long_generated_vents_are_all_counted() {
+    let mix = Mix { horizontal: 1, vertical: 1, diagonal: 1 };
+    let map = generate::vents(&mut Rng::new(3), 20, 50000, mix).unwrap();
+    let longest = map.vents.iter().map(|s| s.0.0.abs_diff(s.1.0).max(s.0.1.abs_diff(s.1.1))).max().unwrap();
+    assert!(longest > 10000);
+    // with every vent twice, every point of the longest one overlaps
+    let doubled = format!("{}{}", map, map);
+    assert!(solve("5.2", &doubled).parse::<usize>().unwrap() > longest);
+    solve("5.1", &doubled);
+}
+
+#[test]
+fn wide_readings_and_far_courses_have_answers() {
+    let report = generate::diag_report(&mut Rng::new(0), 1000, 40).unwrap().to_string();
+    assert!(solve("3.1", &report).parse::<u128>().unwrap() > u64::MAX as u128);
+    solve("3.2", &report);
+    let far = format!("forward {}\ndown 4\n", isize::MAX);
+    assert_eq!(solve("2.1", &far), (isize::MAX as i128 * 4).to_string());
+}
@@ -0,0 +1,164 @@
+//! A packed word of bits of any width, for the day 3 diagnostic readings.
+//!
+//! Bits are numbered from the left, the way a reading is written, so bit 0 of `10110` is the most
+//! significant. They're packed 64 to a `u64` block; bits past the width are always zero, so
+//! counting and comparing can work a block at a time.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::{Index, Not};
+use std::str::FromStr;
+
+const BLOCK: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitWord {
+    width: usize,
+    blocks: Vec<u64>,
+}
+
+impl BitWord {
+    /// A word of `width` zeros
+    pub fn zeros(width: usize) -> Self {
+        BitWord { width, blocks: vec![0; width.div_ceil(BLOCK)] }
+    }
+
+    /// A word of the given bits, most significant first
+    pub fn from_bits<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        let mut word = BitWord::default();
+        for bit in bits {
+            word.push(bit);
+        }
+        word
+    }
+
+    /// Adds a bit on the right, making the word one bit wider
+    pub fn push(&mut self, bit: bool) {
+        if self.width.is_multiple_of(BLOCK) {
+            self.blocks.push(0);
+        }
+        self.width += 1;
+        self.set(self.width - 1, bit);
+    }
+
+    /// The lowest `width` bits of `value`, padded with leading zeros past 64
+    pub fn from_u64(value: u64, width: usize) -> Self {
+        let mut word = BitWord::zeros(width);
+        for power in 0..width.min(BLOCK) {
+            word.set(width - 1 - power, value >> power & 1 == 1);
+        }
+        word
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The `i`th bit from the left. Panics if `i` is past the width.
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.width, "bit {} is past the width of {}", i, self.width);
+        self.blocks[i / BLOCK] >> (i % BLOCK) & 1 == 1
+    }
+
+    /// Sets the `i`th bit from the left. Panics if `i` is past the width.
+    pub fn set(&mut self, i: usize, bit: bool) {
+        assert!(i < self.width, "bit {} is past the width of {}", i, self.width);
+        let mask = 1 << (i % BLOCK);
+        if bit {
+            self.blocks[i / BLOCK] |= mask;
+        } else {
+            self.blocks[i / BLOCK] &= !mask;
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.blocks.iter().map(|block| block.count_ones() as usize).sum()
+    }
+
+    pub fn count_zeros(&self) -> usize {
+        self.width - self.count_ones()
+    }
+
+    /// The bits from the left
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.width).map(|i| self.get(i))
+    }
+
+    /// The packed blocks, bit `i` at position `i % 64` of block `i / 64`
+    pub fn blocks(&self) -> &[u64] {
+        &self.blocks
+    }
+
+    /// The word as a number, or `None` if it's too big for a `u64`
+    pub fn to_u64(&self) -> Option<u64> {
+        let high = self.width.saturating_sub(BLOCK);
+        if (0..high).any(|i| self.get(i)) {
+            return None;
+        }
+        Some((high..self.width).fold(0, |value, i| value << 1 | self.get(i) as u64))
+    }
+}
+
+impl Index<usize> for BitWord {
+    type Output = bool;
+
+    fn index(&self, i: usize) -> &bool {
+        if self.get(i) { &true } else { &false }
+    }
+}
+
+/// Flips every bit within the width
+impl Not for &BitWord {
+    type Output = BitWord;
+
+    fn not(self) -> BitWord {
+        let mut blocks: Vec<u64> = self.blocks.iter().map(|block| !block).collect();
+        if let (Some(last), tail @ 1..) = (blocks.last_mut(), self.width % BLOCK) {
+            *last &= (1 << tail) - 1;
+        }
+        BitWord { width: self.width, blocks }
+    }
+}
+
+impl Not for BitWord {
+    type Output = BitWord;
+
+    fn not(self) -> BitWord {
+        !&self
+    }
+}
+
+impl Display for BitWord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
+
+/// A character that isn't a binary digit, at a byte offset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDigit {
+    pub offset: usize,
+    pub found: char,
+}
+
+impl Display for InvalidDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a binary digit at {}, found `{}`", self.offset, self.found)
+    }
+}
+
+impl Error for InvalidDigit {}
+
+impl FromStr for BitWord {
+    type Err = InvalidDigit;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((offset, found)) = s.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+            return Err(InvalidDigit { offset, found });
+        }
+        Ok(BitWord::from_bits(s.bytes().map(|b| b == b'1')))
+    }
+}
@@ -0,0 +1,54 @@
+use std::str::FromStr;
+use proptest::prelude::*;
+use bitword::{BitWord, InvalidDigit};
+
+#[test]
+fn bits_count_from_the_left() {
+    let word = BitWord::from_str("10110").unwrap();
+    assert_eq!(word.width(), 5);
+    assert!(word[0] && !word[1] && word[3] && !word[4]);
+    assert_eq!(word.to_u64(), Some(22));
+    assert_eq!(word.count_ones(), 3);
+    assert_eq!((!&word).to_string(), "01001");
+    assert_eq!(BitWord::from_u64(22, 7).to_string(), "0010110");
+    assert_eq!(BitWord::from_str("10x"), Err(InvalidDigit { offset: 2, found: 'x' }));
+}
+
+#[test]
+fn words_can_be_wider_than_64_bits() {
+    let mut word = BitWord::from_u64(u64::MAX, 130);
+    assert_eq!(word.count_ones(), 64);
+    assert_eq!(word.to_u64(), Some(u64::MAX));
+    word.set(0, true);
+    assert_eq!(word.to_u64(), None);
+    assert_eq!((!&word).count_ones(), 65);
+    assert_eq!(BitWord::zeros(128), !BitWord::from_bits([true; 128]));
+}
+
+fn word() -> impl Strategy<Value = BitWord> {
+    prop::collection::vec(any::<bool>(), 0..200).prop_map(BitWord::from_bits)
+}
+
+proptest! {
+    #[test]
+    fn words_round_trip_through_text(word in word()) {
+        prop_assert_eq!(BitWord::from_str(&word.to_string()).unwrap(), word);
+    }
+
+    #[test]
+    fn not_flips_only_bits_within_the_width(word in word()) {
+        let flipped = !&word;
+        prop_assert_eq!(flipped.width(), word.width());
+        prop_assert_eq!(flipped.count_ones(), word.count_zeros());
+        prop_assert_eq!(!flipped, word);
+    }
+
+    #[test]
+    fn narrow_words_match_their_numbers(value in any::<u64>(), width in 1..=64usize) {
+        let value = value >> (64 - width);
+        let word = BitWord::from_u64(value, width);
+        prop_assert_eq!(word.to_u64(), Some(value));
+        prop_assert_eq!(word.to_string(), format!("{:0width$b}", value, width = width));
+        prop_assert_eq!(word.count_ones(), value.count_ones() as usize);
+    }
+}
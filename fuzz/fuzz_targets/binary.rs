@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use submarine::binary;
+
+fuzz_target!(|data: &[u8]| {
+    // whatever decodes must come back the same after encoding
+    if let Ok(commands) = binary::decode(data) {
+        assert_eq!(binary::decode(&binary::encode(&commands)).unwrap(), commands);
+    }
+});
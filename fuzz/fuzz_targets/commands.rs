@@ -0,0 +1,17 @@
+#![no_main]
+
+use std::str::FromStr;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        // whatever parses must survive being written back out
+        if let Ok(commands) = submarine::Command::from_lines(s) {
+            let text: String = commands.iter().map(|c| format!("{}\n", c)).collect();
+            assert_eq!(submarine::Command::from_lines(&text).unwrap(), commands);
+        }
+        if let Ok(program) = submarine::Program::from_str(s) {
+            assert_eq!(submarine::Program::from_str(&program.to_string()).unwrap(), program);
+        }
+    }
+});
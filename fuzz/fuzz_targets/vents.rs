@@ -0,0 +1,16 @@
+#![no_main]
+
+use std::str::FromStr;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        // whatever parses must survive being written back out
+        if let Ok(value) = day5_1::Map::from_str(s) {
+            assert_eq!(day5_1::Map::from_str(&value.to_string()).unwrap(), value);
+        }
+        if let Ok(value) = day5_2::Map::from_str(s) {
+            assert_eq!(day5_2::Map::from_str(&value.to_string()).unwrap(), value);
+        }
+    }
+});
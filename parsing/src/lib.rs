@@ -0,0 +1,196 @@
+//! Line and column aware helpers shared by the puzzle input parsers.
+//!
+//! Inputs are walked as numbered [`Line`]s which split into [`Token`]s that remember where they
+//! came from, so a failed parse can point at the offending line and token.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, BufReader, Read};
+use std::str::FromStr;
+
+/// A parse failure at a 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: impl Into<String>) -> Self {
+        ParseError { line, column, token: token.to_string(), message: message.into() }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " at `{}`", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Numbers the lines of an input starting from 1
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// A numbered line read from a reader, which owns its text
+pub struct LineBuf {
+    pub number: usize,
+    pub text: String,
+}
+
+impl LineBuf {
+    pub fn line(&self) -> Line<'_> {
+        Line::new(self.number, &self.text)
+    }
+}
+
+/// Numbers the lines read from any reader starting from 1, without holding the whole input
+pub fn read_lines<R: Read>(reader: R) -> impl Iterator<Item = io::Result<LineBuf>> {
+    BufReader::new(reader).lines().enumerate().map(|(i, text)| Ok(LineBuf { number: i + 1, text: text? }))
+}
+
+/// 1-based column of the byte offset within the text
+fn column(text: &str, offset: usize) -> usize {
+    text[..offset].chars().count() + 1
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Line { number, text }
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// The whole line, minus surrounding whitespace, as a single token
+    pub fn token(&self) -> Token<'a> {
+        Token { line: self.number, column: 1, text: self.text }.trim()
+    }
+
+    /// Whitespace separated tokens
+    pub fn tokens(&self) -> Tokens<'a> {
+        self.token().tokens()
+    }
+
+    /// Tokens between each separator, with surrounding whitespace removed
+    pub fn split(&self, separator: &str) -> Tokens<'a> {
+        self.token().split(separator)
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        self.token().error(message)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Token<'a> {
+    /// A token for `text`, which starts `offset` bytes into this token
+    fn slice(&self, offset: usize, text: &'a str) -> Token<'a> {
+        Token { line: self.line, column: self.column + column(self.text, offset) - 1, text }
+    }
+
+    /// The empty token just past the end of this one, for reporting missing tokens
+    fn end(&self) -> Token<'a> {
+        self.slice(self.text.len(), "")
+    }
+
+    pub fn trim(&self) -> Token<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.slice(start, self.text.trim())
+    }
+
+    /// Whitespace separated tokens
+    pub fn tokens(&self) -> Tokens<'a> {
+        let tokens = self.text.split_ascii_whitespace()
+            .map(|word| self.slice(word.as_ptr() as usize - self.text.as_ptr() as usize, word))
+            .collect::<Vec<_>>();
+        Tokens { tokens: tokens.into_iter(), end: self.end() }
+    }
+
+    /// Tokens between each separator, with surrounding whitespace removed
+    pub fn split(&self, separator: &str) -> Tokens<'a> {
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        let ends = self.text.match_indices(separator).map(|(i, _)| i).chain([self.text.len()]);
+        for end in ends {
+            tokens.push(self.slice(start, &self.text[start..end]).trim());
+            start = end + separator.len();
+        }
+        Tokens { tokens: tokens.into_iter(), end: self.end() }
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+        where T: FromStr, T::Err: Display
+    {
+        self.text.parse::<T>().map_err(|e| self.error(e.to_string()))
+    }
+
+    /// Checks that the token is exactly the expected text
+    pub fn expect(&self, expected: &str) -> Result<(), ParseError> {
+        if self.text == expected {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", expected)))
+        }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column, self.text, message)
+    }
+
+    /// An error pointing at a single character within the token
+    pub fn char_error(&self, offset: usize, message: impl Into<String>) -> ParseError {
+        let len = self.text[offset..].chars().next().map_or(0, char::len_utf8);
+        self.slice(offset, &self.text[offset..offset + len]).error(message)
+    }
+}
+
+/// The tokens of a line or a larger token
+pub struct Tokens<'a> {
+    tokens: std::vec::IntoIter<Token<'a>>,
+    end: Token<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    /// The next token, or an error at the end of the line describing what was missing
+    pub fn expect(&mut self, what: &str) -> Result<Token<'a>, ParseError> {
+        self.next().ok_or_else(|| self.end.error(format!("expected {}", what)))
+    }
+
+    /// Checks that every token has been consumed
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some(token) => Err(token.error("unexpected token")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.next()
+    }
+}
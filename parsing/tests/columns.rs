@@ -0,0 +1,32 @@
+use proptest::prelude::*;
+
+/// The line's text starting at a 1-based column, counted in characters
+fn at_column(line: &str, column: usize) -> &str {
+    let offset = line.char_indices().nth(column - 1).map_or(line.len(), |(i, _)| i);
+    &line[offset..]
+}
+
+proptest! {
+    #[test]
+    fn tokens_point_at_their_text(s in "\\PC*") {
+        for line in parsing::lines(&s) {
+            for token in line.tokens() {
+                prop_assert!(at_column(line.text, token.column).starts_with(token.text));
+            }
+            for token in line.split(",") {
+                prop_assert!(at_column(line.text, token.column).starts_with(token.text));
+            }
+        }
+    }
+
+    #[test]
+    fn char_errors_point_at_the_character(s in "\\PC+", index in any::<prop::sample::Index>()) {
+        let line = parsing::lines(&s).next().unwrap();
+        let token = line.token();
+        prop_assume!(!token.text.is_empty());
+        let (offset, c) = token.text.char_indices().nth(index.index(token.text.chars().count())).unwrap();
+        let err = token.char_error(offset, "bad character");
+        prop_assert_eq!(err.token, c.to_string());
+        prop_assert!(at_column(line.text, err.column).starts_with(c));
+    }
+}
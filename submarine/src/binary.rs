@@ -0,0 +1,176 @@
+//! A compact binary encoding of command streams, for storing and replaying very long courses.
+//!
+//! A stream starts with the bytes `SUBC` and a version byte, followed by one record per command:
+//! an opcode byte, then for every command but `surface` its distance as a zigzag LEB128 varint.
+//! Distances under 64 either way fit in one byte, so a typical `forward 5` takes two bytes instead
+//! of ten. There is no count or terminator; the stream ends where the bytes do.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use anyhow::{Result, anyhow, bail};
+use crate::Command;
+
+pub const MAGIC: &[u8; 4] = b"SUBC";
+pub const VERSION: u8 = 1;
+
+const FORWARD: u8 = 0;
+const DOWN: u8 = 1;
+const UP: u8 = 2;
+const BACK: u8 = 3;
+const SURFACE: u8 = 4;
+const HOLD: u8 = 5;
+const PORT: u8 = 6;
+const STARBOARD: u8 = 7;
+
+/// Writes commands in the binary format as they come
+pub struct Encoder<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Encoder<W> {
+    /// Starts a stream by writing its header
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        Ok(Encoder { writer })
+    }
+
+    pub fn write(&mut self, command: Command) -> io::Result<()> {
+        let (opcode, distance) = match command {
+            Command::Forward(d) => (FORWARD, Some(d)),
+            Command::Down(d) => (DOWN, Some(d)),
+            Command::Up(d) => (UP, Some(d)),
+            Command::Back(d) => (BACK, Some(d)),
+            Command::Surface => (SURFACE, None),
+            Command::Hold(d) => (HOLD, Some(d)),
+            Command::Port(n) => (PORT, Some(n)),
+            Command::Starboard(n) => (STARBOARD, Some(n)),
+        };
+        let mut record = [0; 11];
+        record[0] = opcode;
+        let mut len = 1;
+        if let Some(distance) = distance {
+            // zigzag, so small negative distances stay small too
+            let mut value = (((distance as i64) << 1) ^ ((distance as i64) >> 63)) as u64;
+            loop {
+                let byte = (value & 0x7f) as u8;
+                value >>= 7;
+                record[len] = if value == 0 { byte } else { byte | 0x80 };
+                len += 1;
+                if value == 0 {
+                    break;
+                }
+            }
+        }
+        self.writer.write_all(&record[..len])
+    }
+
+    /// Flushes the stream and hands back the writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Encodes a whole course in memory
+pub fn encode(commands: &[Command]) -> Vec<u8> {
+    let mut encoder = Encoder::new(Vec::new()).expect("writing to a Vec can't fail");
+    for &command in commands {
+        encoder.write(command).expect("writing to a Vec can't fail");
+    }
+    encoder.writer
+}
+
+/// Reads commands in the binary format one at a time, so it can be handed straight to
+/// [`Sub::run_stream`](crate::Sub::run_stream). Iteration stops after the first error.
+pub struct Decoder<R: Read> {
+    reader: BufReader<R>,
+    /// Bytes read so far, for pointing at bad ones
+    offset: u64,
+    failed: bool,
+}
+
+impl<R: Read> Decoder<R> {
+    /// Starts reading a stream, checking its header
+    pub fn new(reader: R) -> Result<Self> {
+        let mut reader = BufReader::new(reader);
+        let mut header = [0; 5];
+        reader.read_exact(&mut header).map_err(|_| anyhow!("too short to be a binary course"))?;
+        if &header[..4] != MAGIC {
+            bail!("not a binary course, it doesn't start with SUBC");
+        }
+        if header[4] != VERSION {
+            bail!("binary course version {} isn't supported, only {}", header[4], VERSION);
+        }
+        Ok(Decoder { reader, offset: header.len() as u64, failed: false })
+    }
+
+    fn byte(&mut self) -> io::Result<Option<u8>> {
+        let mut byte = [0];
+        match self.reader.read(&mut byte)? {
+            0 => Ok(None),
+            _ => {
+                self.offset += 1;
+                Ok(Some(byte[0]))
+            }
+        }
+    }
+
+    fn distance(&mut self) -> Result<isize> {
+        let start = self.offset;
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?.ok_or_else(|| anyhow!("byte {}: course ends inside a distance", start))?;
+            let bits = (byte & 0x7f) as u64;
+            if shift == 63 && bits > 1 {
+                break;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                let distance = (value >> 1) as i64 ^ -((value & 1) as i64);
+                return isize::try_from(distance)
+                    .map_err(|_| anyhow!("byte {}: distance {} is too big", start, distance));
+            }
+        }
+        bail!("byte {}: distance is longer than 64 bits", start)
+    }
+
+    fn command(&mut self) -> Result<Option<Command>> {
+        let at = self.offset;
+        let Some(opcode) = self.byte()? else { return Ok(None) };
+        let command = match opcode {
+            FORWARD => Command::Forward(self.distance()?),
+            DOWN => Command::Down(self.distance()?),
+            UP => Command::Up(self.distance()?),
+            BACK => Command::Back(self.distance()?),
+            SURFACE => Command::Surface,
+            HOLD => Command::Hold(self.distance()?),
+            PORT => Command::Port(self.distance()?),
+            STARBOARD => Command::Starboard(self.distance()?),
+            _ => bail!("byte {}: unknown opcode {}", at, opcode),
+        };
+        Ok(Some(command))
+    }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+    type Item = Result<Command>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let command = self.command();
+        self.failed = command.is_err();
+        command.transpose()
+    }
+}
+
+/// Decodes a whole course held in memory
+pub fn decode(bytes: &[u8]) -> Result<Vec<Command>> {
+    Decoder::new(bytes)?.collect()
+}
+
+/// Whether the buffered start of `reader` is a binary course, without consuming it
+pub fn is_binary<R: BufRead>(reader: &mut R) -> io::Result<bool> {
+    Ok(reader.fill_buf()?.starts_with(MAGIC))
+}
@@ -0,0 +1,92 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::Read;
+use std::str::FromStr;
+use anyhow::Result;
+use parsing::{Line, ParseError};
+
+/// One step of the submarine's course. The puzzle only uses `forward`, `down` and `up`; the rest
+/// come from the extended language of [`Program`](crate::Program).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(isize),
+    Down(isize),
+    Up(isize),
+    /// Reverses along the current course
+    Back(isize),
+    /// Comes straight up to the surface and levels out
+    Surface,
+    /// Moves forward without changing depth, whatever the aim
+    Hold(isize),
+    /// Turns this many quarter turns to port, for models that steer in three dimensions
+    Port(isize),
+    /// Turns this many quarter turns to starboard
+    Starboard(isize),
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(d) => write!(f, "forward {}", d),
+            Command::Down(d) => write!(f, "down {}", d),
+            Command::Up(d) => write!(f, "up {}", d),
+            Command::Back(d) => write!(f, "back {}", d),
+            Command::Surface => write!(f, "surface"),
+            Command::Hold(d) => write!(f, "hold {}", d),
+            Command::Port(n) => write!(f, "port {}", n),
+            Command::Starboard(n) => write!(f, "starboard {}", n),
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_line(&Line::new(1, s))
+    }
+}
+
+impl Command {
+    pub fn from_file(path: &str) -> Result<Vec<Self>> {
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// Parses one command per line from any reader
+    pub fn from_reader<R: Read>(reader: R) -> Result<Vec<Self>> {
+        Self::stream(reader).collect()
+    }
+
+    /// Parses one command per line from any reader as they're needed, holding only one line at a
+    /// time
+    pub fn stream<R: Read>(reader: R) -> impl Iterator<Item = Result<Self>> {
+        parsing::read_lines(reader).map(|line| Ok(Self::parse_line(&line?.line())?))
+    }
+
+    /// Parses one command per line
+    pub fn from_lines(s: &str) -> Result<Vec<Self>, ParseError> {
+        parsing::lines(s).map(|line| Self::parse_line(&line)).collect()
+    }
+
+    pub fn parse_line(line: &Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        let command = tokens.expect("a command")?;
+        let make: fn(isize) -> Command = match command.text {
+            "surface" => {
+                tokens.finish()?;
+                return Ok(Command::Surface);
+            }
+            "forward" => Command::Forward,
+            "up" => Command::Up,
+            "down" => Command::Down,
+            "back" => Command::Back,
+            "hold" => Command::Hold,
+            "port" => Command::Port,
+            "starboard" => Command::Starboard,
+            _ => return Err(command.error("unknown command")),
+        };
+        let parsed = make(tokens.expect("a distance")?.parse::<isize>()?);
+        tokens.finish()?;
+        Ok(parsed)
+    }
+}
@@ -0,0 +1,95 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use anyhow::anyhow;
+use crate::{Command, State};
+
+/// What to do when a command breaks a constraint
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Pull the state back inside the limits and carry on
+    Clamp,
+    /// Stop before the command
+    #[default]
+    Error,
+    /// Note the violation and carry on regardless
+    Warn,
+}
+
+impl FromStr for Mode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clamp" => Ok(Mode::Clamp),
+            "error" => Ok(Mode::Error),
+            "warn" => Ok(Mode::Warn),
+            _ => Err(anyhow!("invalid mode {}, expected clamp, error or warn", s)),
+        }
+    }
+}
+
+/// Limits on where the submarine may go. Arithmetic is always checked, so overflowing a position
+/// is a violation in every mode; clamping skips the command since there is nothing to clamp to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Constraints {
+    /// The shallowest depth allowed, 0 keeps the submarine in the water
+    pub min_depth: Option<isize>,
+    pub max_depth: Option<isize>,
+    /// The steepest aim allowed, up or down
+    pub max_aim: Option<isize>,
+    pub mode: Mode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breach {
+    TooShallow { depth: isize, min: isize },
+    TooDeep { depth: isize, max: isize },
+    TooSteep { aim: isize, max: isize },
+    Overflow,
+}
+
+/// A command that broke a constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    /// The command's position among all the commands the submarine has followed, from 0
+    pub index: usize,
+    pub command: Command,
+    pub breach: Breach,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "command {} ({}): ", self.index, self.command)?;
+        match self.breach {
+            Breach::TooShallow { depth, min } => write!(f, "depth {} is shallower than {}", depth, min),
+            Breach::TooDeep { depth, max } => write!(f, "depth {} is deeper than {}", depth, max),
+            Breach::TooSteep { aim, max } => write!(f, "aim {} is steeper than {}", aim, max),
+            Breach::Overflow => write!(f, "position overflowed"),
+        }
+    }
+}
+
+impl Error for Violation {}
+
+impl Constraints {
+    /// The limits `state` breaks, and the state pulled back inside them
+    pub fn check(&self, state: State) -> (Vec<Breach>, State) {
+        let mut breaches = Vec::new();
+        let mut clamped = state;
+        if let Some(min) = self.min_depth.filter(|&min| state.depth < min) {
+            breaches.push(Breach::TooShallow { depth: state.depth, min });
+            clamped.depth = min;
+        }
+        if let Some(max) = self.max_depth.filter(|&max| state.depth > max) {
+            breaches.push(Breach::TooDeep { depth: state.depth, max });
+            clamped.depth = max;
+        }
+        if let Some(max) = self.max_aim.filter(|&max| state.aim.unsigned_abs() > max.unsigned_abs()) {
+            breaches.push(Breach::TooSteep { aim: state.aim, max });
+            let limit = max.saturating_abs();
+            clamped.aim = if state.aim < 0 { -limit } else { limit };
+        }
+        (breaches, clamped)
+    }
+}
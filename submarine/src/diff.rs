@@ -0,0 +1,173 @@
+//! Lining up two courses to find where they part ways.
+//!
+//! Courses are aligned on their longest common subsequence of commands, so a command inserted or
+//! dropped in one course doesn't make everything after it look different. The alignment is
+//! Myers' linear-space divide and conquer: search from both ends at once for the middle of the
+//! shortest edit script, split the courses there, and align each half. It takes memory
+//! proportional to the lengths of the courses, and time proportional to their lengths times the
+//! number of commands that differ, so long courses that mostly agree are quick to compare.
+
+use std::fmt::{self, Display};
+use crate::{Command, State, Sub, Violation};
+
+/// One line of the alignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// In both courses
+    Same(Command),
+    /// Only in the left course
+    Removed(Command),
+    /// Only in the right course
+    Added(Command),
+}
+
+impl Edit {
+    pub fn command(&self) -> Command {
+        match *self {
+            Edit::Same(command) | Edit::Removed(command) | Edit::Added(command) => command,
+        }
+    }
+}
+
+impl Display for Edit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Edit::Same(command) => write!(f, "  {}", command),
+            Edit::Removed(command) => write!(f, "- {}", command),
+            Edit::Added(command) => write!(f, "+ {}", command),
+        }
+    }
+}
+
+/// Where the courses first disagree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence {
+    /// How many commands the courses start with in common
+    pub index: usize,
+    /// The command each course goes on with, `None` if it ends there
+    pub left: Option<Command>,
+    pub right: Option<Command>,
+    /// Where both submarines were when the courses parted
+    pub state: State,
+}
+
+/// Two courses lined up and followed to their ends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub edits: Vec<Edit>,
+    /// `None` when the courses are the same
+    pub divergence: Option<Divergence>,
+    pub left: State,
+    pub right: State,
+}
+
+impl Comparison {
+    /// How far the right submarine ended from the left, as x, depth, aim and agg
+    pub fn change(&self) -> [i128; 4] {
+        let (l, r) = (self.left, self.right);
+        [
+            r.x as i128 - l.x as i128,
+            r.depth as i128 - l.depth as i128,
+            r.aim as i128 - l.aim as i128,
+            r.agg() - l.agg(),
+        ]
+    }
+}
+
+/// Aligns the two courses, keeping as many commands in common as possible
+pub fn align(left: &[Command], right: &[Command]) -> Vec<Edit> {
+    let mut edits = Vec::with_capacity(left.len().max(right.len()));
+    conquer(left, right, &mut edits);
+    edits
+}
+
+/// Adds the alignment of `a` with `b` to `edits`
+fn conquer(a: &[Command], b: &[Command], edits: &mut Vec<Edit>) {
+    let prefix = a.iter().zip(b).take_while(|(l, r)| l == r).count();
+    edits.extend(a[..prefix].iter().copied().map(Edit::Same));
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a.iter().rev().zip(b.iter().rev()).take_while(|(l, r)| l == r).count();
+    let (a, b, tail) = (&a[..a.len() - suffix], &b[..b.len() - suffix], &a[a.len() - suffix..]);
+    if a.is_empty() || b.is_empty() {
+        edits.extend(a.iter().copied().map(Edit::Removed));
+        edits.extend(b.iter().copied().map(Edit::Added));
+    } else {
+        let (x, y) = middle(a, b);
+        conquer(&a[..x], &b[..y], edits);
+        conquer(&a[x..], &b[y..], edits);
+    }
+    edits.extend(tail.iter().copied().map(Edit::Same));
+}
+
+/// A point about halfway along a shortest edit script from `a` to `b`, which neither start nor end
+/// with the same command, so the script has at least two edits and the point splits it
+fn middle(a: &[Command], b: &[Command]) -> (usize, usize) {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let delta = n - m;
+    let offset = (n + m + 1) / 2 + 1;
+    // the furthest x reached after d edits on each diagonal x - y, from the start of the courses
+    // forwards and from their ends backwards
+    let mut forward = vec![0isize; 2 * offset as usize + 1];
+    let mut backward = forward.clone();
+    for d in 0..offset {
+        for k in (-d..=d).step_by(2) {
+            let i = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && forward[i - 1] < forward[i + 1]) {
+                forward[i + 1]
+            } else {
+                forward[i - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[i] = x;
+            let back = delta - k;
+            if delta % 2 != 0 && back.abs() < d && x + backward[(back + offset) as usize] >= n {
+                return (x as usize, y as usize);
+            }
+        }
+        for k in (-d..=d).step_by(2) {
+            let i = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && backward[i - 1] < backward[i + 1]) {
+                backward[i + 1]
+            } else {
+                backward[i - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[(n - x - 1) as usize] == b[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[i] = x;
+            let ahead = delta - k;
+            if delta % 2 == 0 && ahead.abs() <= d && x + forward[(ahead + offset) as usize] >= n {
+                return ((n - x) as usize, (m - y) as usize);
+            }
+        }
+    }
+    unreachable!("the searches meet by the time they've made every edit between them")
+}
+
+/// Lines up the two courses and follows each with its own submarine, which are left where their
+/// courses end. Fails on the first violation either submarine's constraints turn into an error.
+pub fn compare(
+    left_sub: &mut Sub,
+    left: &[Command],
+    right_sub: &mut Sub,
+    right: &[Command],
+) -> Result<Comparison, Violation> {
+    let edits = align(left, right);
+    let index = edits.iter().take_while(|edit| matches!(edit, Edit::Same(_))).count();
+    left_sub.run_commands(&left[..index])?;
+    let divergence = (index < edits.len()).then(|| Divergence {
+        index,
+        left: left.get(index).copied(),
+        right: right.get(index).copied(),
+        state: left_sub.state,
+    });
+    left_sub.run_commands(&left[index..])?;
+    right_sub.run_commands(right)?;
+    Ok(Comparison { edits, divergence, left: left_sub.state, right: right_sub.state })
+}
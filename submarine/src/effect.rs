@@ -0,0 +1,127 @@
+//! What a run of commands does to the submarine, as a value that can be combined.
+//!
+//! Under both built in models every command moves the state by an affine map: the new `x`, depth
+//! and aim are sums of multiples of the old ones plus a constant. Affine maps compose into affine
+//! maps, and composing is associative, so a long course can be split into chunks whose effects are
+//! worked out in parallel and then combined in order. The combined effect lands the submarine in
+//! the same place as following the commands one by one. Partial sums are taken in a different
+//! order, so a course that overflows part way but comes back in range can still have an effect.
+
+use rayon::prelude::*;
+use crate::{Command, State, SteeringModel};
+
+/// The affine map `state -> matrix * state + offset`, with states as `[x, depth, aim]`. The
+/// lateral position and heading of three dimensional states are left as they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Effect {
+    pub matrix: [[isize; 3]; 3],
+    pub offset: [isize; 3],
+}
+
+impl Default for Effect {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Rows and columns of the state vector
+const X: usize = 0;
+const DEPTH: usize = 1;
+const AIM: usize = 2;
+
+impl Effect {
+    /// Doing nothing
+    pub const IDENTITY: Effect = Effect { matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]], offset: [0; 3] };
+
+    /// Adds `amount` to one part of the state
+    fn shift(part: usize, amount: isize) -> Self {
+        let mut effect = Self::IDENTITY;
+        effect.offset[part] = amount;
+        effect
+    }
+
+    /// Moves `dx` along with depth following the aim
+    fn glide(dx: isize) -> Self {
+        let mut effect = Self::shift(X, dx);
+        effect.matrix[DEPTH][AIM] = dx;
+        effect
+    }
+
+    /// Zeroes the given parts of the state
+    fn reset(parts: &[usize]) -> Self {
+        let mut effect = Self::IDENTITY;
+        for &part in parts {
+            effect.matrix[part][part] = 0;
+        }
+        effect
+    }
+
+    /// This effect followed by `next`, or `None` if a coefficient overflows
+    pub fn then(&self, next: &Effect) -> Option<Effect> {
+        let mut combined = Effect { matrix: [[0; 3]; 3], offset: next.offset };
+        for row in 0..3 {
+            for col in 0..3 {
+                let mut sum: isize = 0;
+                for k in 0..3 {
+                    sum = sum.checked_add(next.matrix[row][k].checked_mul(self.matrix[k][col])?)?;
+                }
+                combined.matrix[row][col] = sum;
+            }
+            for k in 0..3 {
+                let moved = next.matrix[row][k].checked_mul(self.offset[k])?;
+                combined.offset[row] = combined.offset[row].checked_add(moved)?;
+            }
+        }
+        Some(combined)
+    }
+
+    /// Where this effect takes `state`, or `None` if the position overflows
+    pub fn apply(&self, state: State) -> Option<State> {
+        let old = [state.x, state.depth, state.aim];
+        let mut new = self.offset;
+        for (row, value) in new.iter_mut().enumerate() {
+            for (k, old) in old.iter().enumerate() {
+                *value = value.checked_add(self.matrix[row][k].checked_mul(*old)?)?;
+            }
+        }
+        Some(State { x: new[X], depth: new[DEPTH], aim: new[AIM], ..state })
+    }
+
+    /// The effect of following every command in turn, or `None` if the model has no effects or a
+    /// coefficient overflows
+    pub fn of(model: &dyn SteeringModel, commands: &[Command]) -> Option<Effect> {
+        commands.iter().try_fold(Effect::IDENTITY, |effect, command| effect.then(&model.effect(*command)?))
+    }
+
+    /// [`Effect::of`], worked out in parallel chunks
+    pub fn par_of(model: &dyn SteeringModel, commands: &[Command]) -> Option<Effect> {
+        commands.par_iter()
+            .map(|command| model.effect(*command))
+            .try_reduce(|| Effect::IDENTITY, |first, second| first.then(&second))
+    }
+}
+
+/// The effect of a command under the direct model, or `None` if its distance can't be negated
+pub fn direct(command: Command) -> Option<Effect> {
+    Some(match command {
+        Command::Forward(d) | Command::Hold(d) => Effect::shift(X, d),
+        Command::Back(d) => Effect::shift(X, d.checked_neg()?),
+        Command::Down(d) => Effect::shift(DEPTH, d),
+        Command::Up(d) => Effect::shift(DEPTH, d.checked_neg()?),
+        Command::Surface => Effect::reset(&[DEPTH]),
+        Command::Port(_) | Command::Starboard(_) => Effect::IDENTITY,
+    })
+}
+
+/// The effect of a command under the aim model, or `None` if its distance can't be negated
+pub fn aim(command: Command) -> Option<Effect> {
+    Some(match command {
+        Command::Forward(d) => Effect::glide(d),
+        Command::Back(d) => Effect::glide(d.checked_neg()?),
+        Command::Hold(d) => Effect::shift(X, d),
+        Command::Down(d) => Effect::shift(AIM, d),
+        Command::Up(d) => Effect::shift(AIM, d.checked_neg()?),
+        Command::Surface => Effect::reset(&[DEPTH, AIM]),
+        Command::Port(_) | Command::Starboard(_) => Effect::IDENTITY,
+    })
+}
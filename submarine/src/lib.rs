@@ -0,0 +1,37 @@
+//! The submarine from day 2, which follows a stream of commands.
+//!
+//! How a command moves the submarine is up to its [`SteeringModel`]: part 1 of the puzzle reads
+//! `down` and `up` as changes in depth ([`Direct`]), part 2 as changes in aim ([`Aim`]). Other
+//! interpretations of the same commands only need another model, such as the [`Navigator`] that
+//! turns to `port` and `starboard` to steer in three dimensions. A [`Trace`] can record every
+//! state along the way, [`Constraints`] keep it within safe limits, and two courses can be lined up
+//! to see where they part ways with [`diff::compare`].
+//!
+//! Courses can also be written as a [`Program`], which adds more verbs, repeated blocks and
+//! comments to the puzzle's one command per line, or typed in a line at a time in a
+//! [`repl::Session`]. Very long courses can be stored compactly in the [`binary`] format, and
+//! shortened with [`optimize`].
+
+pub mod binary;
+pub mod command;
+pub mod constraints;
+pub mod diff;
+pub mod effect;
+pub mod model;
+pub mod optimize;
+pub mod plan;
+pub mod program;
+pub mod render;
+pub mod repl;
+pub mod sub;
+pub mod trace;
+
+pub use command::Command;
+pub use constraints::{Breach, Constraints, Mode, Violation};
+pub use diff::{Comparison, Divergence, Edit};
+pub use effect::Effect;
+pub use model::{Aim, Direct, Heading, Navigator, State, SteeringModel};
+pub use plan::{Limits, PlanError, Target};
+pub use program::{Program, Statement};
+pub use sub::Sub;
+pub use trace::{Step, Trace};
@@ -0,0 +1,235 @@
+use std::fmt::{self, Display};
+use anyhow::anyhow;
+use crate::effect::{self, Effect};
+use crate::plan::{self, Limits, Target};
+use crate::Command;
+
+/// Which way the submarine faces. `x` runs east and the lateral position north, and a submarine
+/// starts out heading east, so models that only steer in two dimensions never leave that plane.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading {
+    #[default]
+    East,
+    North,
+    West,
+    South,
+}
+
+impl Heading {
+    const ALL: [Heading; 4] = [Heading::East, Heading::North, Heading::West, Heading::South];
+
+    /// The heading after `quarters` quarter turns to port, or to starboard if negative
+    pub fn turn(self, quarters: isize) -> Heading {
+        let turns = (self as isize + quarters.rem_euclid(4)) % 4;
+        Self::ALL[turns as usize]
+    }
+
+    /// How far east and north moving `distance` ahead goes, or `None` if it can't be negated
+    pub fn along(self, distance: isize) -> Option<(isize, isize)> {
+        Some(match self {
+            Heading::East => (distance, 0),
+            Heading::North => (0, distance),
+            Heading::West => (distance.checked_neg()?, 0),
+            Heading::South => (0, distance.checked_neg()?),
+        })
+    }
+}
+
+impl Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Heading::East => "east",
+            Heading::North => "north",
+            Heading::West => "west",
+            Heading::South => "south",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Where the submarine is. Models that don't steer by aim leave it at 0, and models that don't
+/// turn leave the lateral position at 0 and the heading east.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub x: isize,
+    pub depth: isize,
+    pub aim: isize,
+    pub lateral: isize,
+    pub heading: Heading,
+}
+
+impl State {
+    /// A state in the plane the two dimensional models move in
+    pub const fn new(x: isize, depth: isize, aim: isize) -> Self {
+        State { x, depth, aim, lateral: 0, heading: Heading::East }
+    }
+
+    /// The puzzle's answer, `x * depth`, wide enough that it can't overflow
+    pub fn agg(&self) -> i128 {
+        self.x as i128 * self.depth as i128
+    }
+
+    /// This state seen side on, in the plane of the two dimensional models
+    pub fn projection(&self) -> State {
+        State::new(self.x, self.depth, self.aim)
+    }
+}
+
+/// Shows the lateral position and heading only once the submarine has left the plane
+impl Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "x {}, depth {}, aim {}", self.x, self.depth, self.aim)?;
+        if *self != self.projection() {
+            write!(f, ", lateral {}, heading {}", self.lateral, self.heading)?;
+        }
+        Ok(())
+    }
+}
+
+/// An interpretation of the submarine commands. Models are shared between threads when courses are
+/// reduced in parallel.
+pub trait SteeringModel: Send + Sync {
+    /// The name the model is picked by on the command line
+    fn name(&self) -> &str;
+
+    /// The state after following one command, or `None` if the position overflows
+    fn step(&self, state: State, command: Command) -> Option<State>;
+
+    /// The shortest course from the surface to `target` the model can find, for models that know
+    /// how to plan one. Use [`plan::plan`] to have the course checked.
+    fn plan(&self, _target: Target, _limits: &Limits) -> Option<Vec<Command>> {
+        None
+    }
+
+    /// What one command does to any state, for models whose commands are affine maps. This lets
+    /// long courses be reduced in parallel; see [`Effect`].
+    fn effect(&self, _command: Command) -> Option<Effect> {
+        None
+    }
+}
+
+/// Part 1: `down` and `up` change the depth directly
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Direct;
+
+impl SteeringModel for Direct {
+    fn name(&self) -> &str {
+        "direct"
+    }
+
+    fn step(&self, state: State, command: Command) -> Option<State> {
+        let State { x, depth, .. } = state;
+        Some(match command {
+            Command::Forward(d) | Command::Hold(d) => State { x: x.checked_add(d)?, ..state },
+            Command::Down(d) => State { depth: depth.checked_add(d)?, ..state },
+            Command::Up(d) => State { depth: depth.checked_sub(d)?, ..state },
+            Command::Back(d) => State { x: x.checked_sub(d)?, ..state },
+            Command::Surface => State { depth: 0, ..state },
+            Command::Port(_) | Command::Starboard(_) => state,
+        })
+    }
+
+    fn plan(&self, target: Target, limits: &Limits) -> Option<Vec<Command>> {
+        Some(plan::direct(target, limits))
+    }
+
+    fn effect(&self, command: Command) -> Option<Effect> {
+        effect::direct(command)
+    }
+}
+
+/// Part 2: `down` and `up` tilt the submarine, and moving forward dives along the aim
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Aim;
+
+impl SteeringModel for Aim {
+    fn name(&self) -> &str {
+        "aim"
+    }
+
+    fn step(&self, state: State, command: Command) -> Option<State> {
+        let State { x, depth, aim, .. } = state;
+        Some(match command {
+            Command::Forward(d) => {
+                State { x: x.checked_add(d)?, depth: depth.checked_add(aim.checked_mul(d)?)?, ..state }
+            }
+            Command::Down(d) => State { aim: aim.checked_add(d)?, ..state },
+            Command::Up(d) => State { aim: aim.checked_sub(d)?, ..state },
+            Command::Back(d) => {
+                State { x: x.checked_sub(d)?, depth: depth.checked_sub(aim.checked_mul(d)?)?, ..state }
+            }
+            Command::Surface => State { depth: 0, aim: 0, ..state },
+            Command::Hold(d) => State { x: x.checked_add(d)?, ..state },
+            Command::Port(_) | Command::Starboard(_) => state,
+        })
+    }
+
+    fn plan(&self, target: Target, limits: &Limits) -> Option<Vec<Command>> {
+        Some(plan::aim(target, limits))
+    }
+
+    fn effect(&self, command: Command) -> Option<Effect> {
+        effect::aim(command)
+    }
+}
+
+/// Steers in three dimensions: `port` and `starboard` turn the submarine, and every other command
+/// moves it as the two dimensional model `M` would, but along its heading. Seen side on, a course
+/// without turns goes exactly where it would under `M`.
+#[derive(Debug, Clone)]
+pub struct Navigator<M> {
+    model: M,
+    name: String,
+}
+
+impl<M: SteeringModel> Navigator<M> {
+    pub fn new(model: M) -> Self {
+        let name = format!("{}-3d", model.name());
+        Navigator { model, name }
+    }
+}
+
+impl<M: SteeringModel> SteeringModel for Navigator<M> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn step(&self, state: State, command: Command) -> Option<State> {
+        let heading = match command {
+            Command::Port(n) => state.heading.turn(n),
+            Command::Starboard(n) => state.heading.turn(n.rem_euclid(4).wrapping_neg()),
+            _ => {
+                // move as if heading east from the origin, then turn the distance covered
+                let moved = self.model.step(State::new(0, state.depth, state.aim), command)?;
+                let (east, north) = state.heading.along(moved.x)?;
+                return Some(State {
+                    x: state.x.checked_add(east)?,
+                    depth: moved.depth,
+                    aim: moved.aim,
+                    lateral: state.lateral.checked_add(north)?,
+                    heading: state.heading,
+                });
+            }
+        };
+        Some(State { heading, ..state })
+    }
+}
+
+/// The names of the models that come with the crate
+pub const BUILTIN: [&str; 4] = ["direct", "aim", "direct-3d", "aim-3d"];
+
+/// One of the models that come with the crate, by name
+pub fn builtin(name: &str) -> Option<Box<dyn SteeringModel>> {
+    match name {
+        "direct" => Some(Box::new(Direct)),
+        "aim" => Some(Box::new(Aim)),
+        "direct-3d" => Some(Box::new(Navigator::new(Direct))),
+        "aim-3d" => Some(Box::new(Navigator::new(Aim))),
+        _ => None,
+    }
+}
+
+/// One of the models that come with the crate, by name, or an error listing them
+pub fn find(name: &str) -> anyhow::Result<Box<dyn SteeringModel>> {
+    builtin(name).ok_or_else(|| anyhow!("unknown steering model {}, expected one of: {}", name, BUILTIN.join(", ")))
+}
@@ -0,0 +1,118 @@
+//! Shortening courses without changing where they end.
+//!
+//! The optimizer works through a course keeping a list of the commands so far. Each new command is
+//! merged into an earlier one where it can be: `down 3` and `down 2` become `down 5`, `down 3` and
+//! `up 3` cancel out, and `down 4` then `surface` is just `surface` under the aim model. A command
+//! can reach back past a few commands it commutes with, so `down 1`, `hold 5`, `down 2` folds into
+//! `down 3`, `hold 5`. When commands cancel, their neighbours get another chance to merge.
+//!
+//! Every rewrite is checked against the model's [`Effect`]s: a merge only happens if the merged
+//! commands have exactly the same effect as the originals, so the optimized course ends in the
+//! same state from any start, as long as neither course overflows on the way. The path there can
+//! be different, so constraints that held along the original course might not along the optimized
+//! one. Models without effects, such as the three dimensional navigators, get their course back
+//! unchanged.
+
+use crate::{Command, Effect, SteeringModel};
+
+/// How many commands a command may reach back past to find one to merge with
+const WINDOW: usize = 8;
+
+/// A shorter course with the same effect under `model`, or the same course if the model has no
+/// effects
+pub fn optimize(model: &dyn SteeringModel, commands: &[Command]) -> Vec<Command> {
+    let Some(effects) = commands.iter().map(|&c| model.effect(c)).collect::<Option<Vec<_>>>() else {
+        return commands.to_vec();
+    };
+    let mut course = Vec::with_capacity(commands.len());
+    for (&command, effect) in commands.iter().zip(effects) {
+        push(model, &mut course, command, effect);
+    }
+    course.into_iter().map(|(command, _)| command).collect()
+}
+
+fn push(model: &dyn SteeringModel, course: &mut Vec<(Command, Effect)>, command: Command, effect: Effect) {
+    if effect == Effect::IDENTITY {
+        return;
+    }
+    for i in (course.len().saturating_sub(WINDOW)..course.len()).rev() {
+        let (earlier, earlier_effect) = course[i];
+        if let Some(merged) = merge(model, (earlier, &earlier_effect), (command, &effect)) {
+            // what follows might now merge with the merged command or its new neighbour
+            let mut rest: Vec<_> = course.drain(i..).skip(1).collect();
+            if let Some(merged) = merged {
+                rest.insert(0, merged);
+            }
+            for (command, effect) in rest {
+                push(model, course, command, effect);
+            }
+            return;
+        }
+        if earlier_effect.then(&effect) != effect.then(&earlier_effect) {
+            break;
+        }
+    }
+    course.push((command, effect));
+}
+
+/// A replacement for `first` followed by `second`: `Some(None)` if they cancel out, or a single
+/// command with the same effect
+fn merge(
+    model: &dyn SteeringModel,
+    (first, first_effect): (Command, &Effect),
+    (second, second_effect): (Command, &Effect),
+) -> Option<Option<(Command, Effect)>> {
+    let both = first_effect.then(second_effect)?;
+    if both == Effect::IDENTITY {
+        return Some(None);
+    }
+    let combined = combine(first, second);
+    [combined, Some(first), Some(second)]
+        .into_iter()
+        .flatten()
+        .find_map(|command| model.effect(command).filter(|effect| *effect == both).map(|e| (command, e)))
+        .map(Some)
+}
+
+/// The one command that moves as far along the same axis as both, if they share one
+fn combine(first: Command, second: Command) -> Option<Command> {
+    let (axis, a) = signed(first)?;
+    let (other, b) = signed(second)?;
+    if axis != other {
+        return None;
+    }
+    let total = a.checked_add(b)?;
+    // keep distances positive where there's a command for the other way
+    let back = total.checked_neg().filter(|_| total < 0);
+    Some(match (axis, back) {
+        (Axis::Along, Some(back)) => Command::Back(back),
+        (Axis::Along, None) => Command::Forward(total),
+        (Axis::Depth, Some(back)) => Command::Up(back),
+        (Axis::Depth, None) => Command::Down(total),
+        (Axis::Level, _) => Command::Hold(total),
+        (Axis::Turn, Some(back)) => Command::Starboard(back),
+        (Axis::Turn, None) => Command::Port(total),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Along,
+    Depth,
+    Level,
+    Turn,
+}
+
+/// The axis a command moves along and how far, counting `back`, `up` and `starboard` as negative
+fn signed(command: Command) -> Option<(Axis, isize)> {
+    Some(match command {
+        Command::Forward(d) => (Axis::Along, d),
+        Command::Back(d) => (Axis::Along, d.checked_neg()?),
+        Command::Down(d) => (Axis::Depth, d),
+        Command::Up(d) => (Axis::Depth, d.checked_neg()?),
+        Command::Hold(d) => (Axis::Level, d),
+        Command::Port(n) => (Axis::Turn, n),
+        Command::Starboard(n) => (Axis::Turn, n.checked_neg()?),
+        Command::Surface => return None,
+    })
+}
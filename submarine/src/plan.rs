@@ -0,0 +1,481 @@
+//! Working backwards from a position to a course that reaches it.
+//!
+//! Under the direct model `x` and depth move independently, so the shortest course just covers
+//! each distance in as few commands as the magnitude limit allows. Under the aim model depth only
+//! changes while moving at an angle, so a course sets an aim, moves along it, and makes up the
+//! rest of the horizontal distance level, before diving. With no magnitude limit that takes at most
+//! three commands and the plan is minimal.
+//!
+//! With a limit, the planner first builds the best course it can from a few legs, then searches
+//! for a shorter one. Turning down by `a` while `along` is still to be covered adds `a * along` to
+//! the final depth, whatever order the moves after it come in, so the search only has to track how
+//! far the course still has to go along and down, not the aim. It's an A* search, bounded by the
+//! fewest commands that could cover what's left: the moves to close the distance, and the turns to
+//! take up the depth at the furthest point the course could swing out to.
+//!
+//! With limits up to 8 the search tries every command, so the course it finds is the shortest.
+//! Past that it only tries the largest moves and turns, a few small ones, and the ones that line up
+//! with the target, so its work doesn't grow with the limit, but it can miss a course a command or
+//! two shorter. Either way it gives up after a fixed number of steps and keeps the course built
+//! from legs, which for deep targets can be a few commands longer than the shortest.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use crate::{Command, State, SteeringModel, Sub, Violation};
+
+/// Where the course should end up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub x: isize,
+    pub depth: isize,
+}
+
+/// Bounds on the course
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The largest distance any one command may carry
+    pub max_magnitude: Option<isize>,
+    /// The most commands the course may have
+    pub max_commands: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    /// The model doesn't know how to plan a course
+    Unsupported(String),
+    /// Commands need room for a distance of at least 1
+    BadMagnitude(isize),
+    /// The shortest course the planner found is longer than allowed
+    TooLong { needed: usize, max: usize },
+    /// Following the planned course broke a constraint, such as overflowing
+    Violation(Violation),
+    /// Following the planned course ended up somewhere else
+    Missed { reached: State },
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::Unsupported(model) => write!(f, "the {} model can't plan courses", model),
+            PlanError::BadMagnitude(max) => {
+                write!(f, "the largest command magnitude must be at least 1, not {}", max)
+            }
+            PlanError::TooLong { needed, max } => {
+                write!(f, "the course needs {} commands, more than the {} allowed", needed, max)
+            }
+            PlanError::Violation(violation) => write!(f, "the course breaks a constraint: {}", violation),
+            PlanError::Missed { reached } => {
+                write!(f, "the course ends at x {}, depth {} instead", reached.x, reached.depth)
+            }
+        }
+    }
+}
+
+impl Error for PlanError {}
+
+/// Plans a course to `target` under `model`, and checks it by following it from the surface
+pub fn plan(
+    model: Box<dyn SteeringModel>,
+    target: Target,
+    limits: &Limits,
+) -> Result<Vec<Command>, PlanError> {
+    if let Some(max) = limits.max_magnitude.filter(|&max| max < 1) {
+        return Err(PlanError::BadMagnitude(max));
+    }
+    let course = model.plan(target, limits).ok_or_else(|| PlanError::Unsupported(model.name().to_string()))?;
+    if let Some(max) = limits.max_commands.filter(|&max| course.len() > max) {
+        return Err(PlanError::TooLong { needed: course.len(), max });
+    }
+    let mut sub = Sub::new(model);
+    sub.run_commands(&course).map_err(PlanError::Violation)?;
+    if (sub.state.x, sub.state.depth) != (target.x, target.depth) {
+        return Err(PlanError::Missed { reached: sub.state });
+    }
+    Ok(course)
+}
+
+/// The magnitude limit, or the largest distance a command can carry
+fn magnitude(limits: &Limits) -> i128 {
+    limits.max_magnitude.unwrap_or(isize::MAX) as i128
+}
+
+/// How many commands it takes to cover `distance`
+fn cost(distance: i128, max: i128) -> usize {
+    let distance = distance.unsigned_abs();
+    distance.div_ceil(max as u128) as usize
+}
+
+/// Splits `distance` into commands of at most `max`, made by `command` from positive distances
+/// or `opposite` from negative ones
+fn cover(
+    course: &mut Vec<Command>,
+    distance: i128,
+    max: i128,
+    command: fn(isize) -> Command,
+    opposite: fn(isize) -> Command,
+) {
+    let make = if distance < 0 { opposite } else { command };
+    let mut left = distance.abs();
+    while left > 0 {
+        let step = left.min(max);
+        course.push(make(step as isize));
+        left -= step;
+    }
+}
+
+/// The shortest course under the direct model
+pub fn direct(target: Target, limits: &Limits) -> Vec<Command> {
+    let max = magnitude(limits);
+    let mut course = Vec::new();
+    cover(&mut course, target.x as i128, max, Command::Forward, Command::Back);
+    cover(&mut course, target.depth as i128, max, Command::Down, Command::Up);
+    course
+}
+
+/// A stretch of an aim model course: turn to `aim`, then move `dx` along it
+#[derive(Debug, Clone, Copy)]
+struct Leg {
+    aim: i128,
+    dx: i128,
+}
+
+/// An aim model course: move `level` at aim 0, then follow each leg
+#[derive(Debug, Clone)]
+struct AimCourse {
+    level: i128,
+    legs: Vec<Leg>,
+}
+
+impl AimCourse {
+    fn cost(&self, max: i128) -> usize {
+        let mut aim = 0;
+        let mut total = cost(self.level, max);
+        for leg in &self.legs {
+            total += cost(leg.aim - aim, max) + cost(leg.dx, max);
+            aim = leg.aim;
+        }
+        total
+    }
+
+    fn commands(&self, max: i128) -> Vec<Command> {
+        let mut course = Vec::new();
+        let mut aim = 0;
+        cover(&mut course, self.level, max, Command::Forward, Command::Back);
+        for leg in &self.legs {
+            cover(&mut course, leg.aim - aim, max, Command::Down, Command::Up);
+            cover(&mut course, leg.dx, max, Command::Forward, Command::Back);
+            aim = leg.aim;
+        }
+        course
+    }
+}
+
+/// The cheapest course seen so far
+struct Best {
+    course: AimCourse,
+    cost: usize,
+    max: i128,
+}
+
+impl Best {
+    fn consider(&mut self, course: AimCourse) {
+        let cost = course.cost(self.max);
+        if cost < self.cost {
+            self.cost = cost;
+            self.course = course;
+        }
+    }
+}
+
+/// The shortest course the planner finds under the aim model
+pub fn aim(target: Target, limits: &Limits) -> Vec<Command> {
+    let max = magnitude(limits);
+    let (x, depth) = (target.x as i128, target.depth as i128);
+    if depth == 0 {
+        return AimCourse { level: x, legs: Vec::new() }.commands(max);
+    }
+    let leg = |aim: i128, dx: i128| Leg { aim, dx };
+    // a single step at an aim of the whole depth always works
+    let course = AimCourse { level: x - 1, legs: vec![leg(depth, 1)] };
+    let mut best = Best { cost: course.cost(max), course, max };
+    if x != 0 && depth % x == 0 {
+        best.consider(AimCourse { level: 0, legs: vec![leg(depth / x, x)] });
+    }
+    if limits.max_magnitude.is_none() {
+        return best.course.commands(max);
+    }
+    // nothing can beat this, so once a course matches it there's no need to look further
+    let fewest = Node { along: x.abs(), depth, outward: true }.bound(max).unwrap_or(0);
+    let size = depth.abs();
+    // moving forwards needs an aim the same way as the depth, moving back the opposite
+    for dir in [1, -1] {
+        let sign = depth.signum() * dir;
+        // a single aim that divides the depth, trying divisors up to a million and their partners
+        let mut a = 1;
+        while a * a <= size && a <= DIVISORS && cost(a, max) < best.cost && best.cost > fewest {
+            if size % a == 0 {
+                for (aim, dx) in [(a, size / a), (size / a, a)] {
+                    best.consider(AimCourse { level: x - dir * dx, legs: vec![leg(sign * aim, dir * dx)] });
+                }
+            }
+            a += 1;
+        }
+        // a shallow leg for the remainder, then whole turns of the largest magnitude
+        let mut turns = 1;
+        while (turns as usize) < best.cost && turns * max <= size && best.cost > fewest {
+            let full = turns * max;
+            let (moves, rest) = (size / full, size % full);
+            let mut legs = Vec::new();
+            if rest > 0 {
+                legs.push(leg(sign * rest, dir));
+            }
+            legs.push(leg(sign * full, dir * moves));
+            let moved: i128 = legs.iter().map(|leg| leg.dx).sum();
+            best.consider(AimCourse { level: x - moved, legs });
+            turns += 1;
+        }
+    }
+    let course = best.course.commands(max);
+    if course.len() == fewest {
+        return course;
+    }
+    search(x, depth, max, course.len()).unwrap_or(course)
+}
+
+/// The largest divisor of the depth tried for a course with a single aim
+const DIVISORS: i128 = 1 << 20;
+/// Distances and turns up to this are all tried; past it the search only tries the largest and the
+/// ones that line up with the target
+const EVERY: i128 = 8;
+/// How many nodes the search expands before settling for the course built from legs
+const BUDGET: usize = 20_000;
+/// How many turn counts either side of the likeliest are tried when bounding a swing out
+const SCAN: usize = 16;
+/// How many single-turn finishes are tried from one node
+const FINISHES: i128 = 64;
+
+/// Where the search for an aim course has got to: what's left to cover. `along` is measured away
+/// from the target on the side the course starts, since crossing over it never helps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Node {
+    along: i128,
+    depth: i128,
+    /// Whether the course may still swing further out; once it heads in, it keeps heading in
+    outward: bool,
+}
+
+impl Node {
+    /// The fewest commands that could finish the course from here, or `None` if it can't be
+    /// finished
+    fn bound(&self, max: i128) -> Option<usize> {
+        let inward = closing(self.along, self.depth, max);
+        if !self.outward || self.depth == 0 {
+            return inward;
+        }
+        Some(inward.unwrap_or(usize::MAX).min(swinging(self.along, self.depth, max)))
+    }
+
+    /// The nodes one command on, with the likeliest last
+    fn next(&self, side: i128, max: i128) -> Vec<(Node, Command)> {
+        let small = 1..=max.min(EVERY);
+        let mut next = Vec::new();
+        let mut moves: Vec<i128> = small.clone().chain([max, self.along % max]).collect();
+        moves.sort_unstable();
+        moves.dedup();
+        for &d in moves.iter().filter(|&&d| d > 0 && d <= self.along) {
+            let node = Node { along: self.along - d, outward: false, ..*self };
+            next.push((node, towards(side, d)));
+        }
+        if self.along != 0 {
+            let down = if self.depth * side > 0 { 1 } else { -1 };
+            let mut aims: Vec<i128> = small.clone().flat_map(|a| [-down * a, down * a]).collect();
+            aims.extend([-down * max, down * max]);
+            // as far as a single turn can take the depth towards zero, or all the way
+            let level = self.depth / (side * self.along);
+            aims.push(level.clamp(-max, max));
+            aims.retain(|&aim| aim != 0);
+            let mut tried = Vec::with_capacity(aims.len());
+            for aim in aims {
+                if tried.contains(&aim) {
+                    continue;
+                }
+                tried.push(aim);
+                // past this depths can't be doubled, and no course worth finding goes near them
+                let depth = aim.checked_mul(side * self.along).and_then(|dive| self.depth.checked_sub(dive));
+                let Some(depth) = depth.filter(|depth| depth.unsigned_abs() < 1 << 126) else { continue };
+                let command = if aim > 0 { Command::Down(aim as isize) } else { Command::Up(-aim as isize) };
+                next.push((Node { depth, ..*self }, command));
+            }
+        }
+        if self.outward {
+            for d in small.chain([max]) {
+                if let Some(along) = self.along.checked_add(d) {
+                    next.push((Node { along, ..*self }, towards(-side, d)));
+                }
+            }
+        }
+        next
+    }
+}
+
+/// A move of `distance` towards the target on `side`
+fn towards(side: i128, distance: i128) -> Command {
+    if side > 0 { Command::Forward(distance as isize) } else { Command::Back(distance as isize) }
+}
+
+/// The fewest commands that could finish a course that only heads in from here
+fn closing(along: i128, depth: i128, max: i128) -> Option<usize> {
+    let moves = cost(along, max);
+    if depth == 0 {
+        return Some(moves);
+    }
+    if along == 0 {
+        return None;
+    }
+    // with every move a whole `max` the course only stops at multiples of it, where every turn
+    // takes up a multiple of it too, so one move has to be shorter and then there's one more
+    let rigid = (along % max == 0 && depth % max != 0) as usize;
+    // every turn takes up at most `max * along`
+    let turns = max.checked_mul(along).map_or(1, |reach| cost(depth, reach));
+    if turns > 1 {
+        return Some(moves + turns + rigid);
+    }
+    let (stops, every) = stops(along, depth, max);
+    let once = match every {
+        true => stops.into_iter().map(|stop| finishing(along, stop, max)).min().unwrap_or(usize::MAX),
+        false => moves + 1 + rigid,
+    };
+    Some(once.min(moves + 2 + rigid))
+}
+
+/// The fewest commands that could finish a course that first swings further out from here
+fn swinging(along: i128, depth: i128, max: i128) -> usize {
+    // Out to a furthest point `peak` and back in takes at least cost(peak - along) + cost(peak)
+    // moves, and the turns, which take up at most `max * peak` each, at least
+    // cost(depth, max * peak). With `turns` turns the nearest peak far enough out is best, and
+    // the total is then at least turns + (2 * depth / (max * turns) - along) / max, which only
+    // grows either side of turns = sqrt(2 * depth) / max. So the scan works out from there, and
+    // on each side stops once that passes the best it has, or settles for it after a few turns.
+    let (along, depth, max) = (along as u128, depth.unsigned_abs(), max as u128);
+    // past this many turns the peak is as near as it can be, so more only cost more
+    let most = max.checked_mul(along + 1).map_or(1, |reach| depth.div_ceil(reach));
+    let total = |turns: u128| {
+        let peak = (along + 1).max(depth.div_ceil(max * turns));
+        (turns + (peak - along).div_ceil(max) + peak.div_ceil(max)) as i128
+    };
+    let least = |turns: u128| {
+        let reach = 2 * depth / (max * turns);
+        turns as i128 + (reach as i128 - along as i128).div_euclid(max as i128)
+    };
+    let middle = (2 * depth).isqrt() / max;
+    let mut best = i128::MAX;
+    for (mut turns, step) in [(middle.clamp(1, most), -1), (middle + 1, 1)] {
+        let mut scanned = 0;
+        while (1..=most).contains(&turns) && least(turns) < best {
+            if scanned == SCAN {
+                best = least(turns);
+                break;
+            }
+            best = best.min(total(turns));
+            turns = turns.wrapping_add_signed(step);
+            scanned += 1;
+        }
+    }
+    best.max(0) as usize
+}
+
+/// The stops heading in from `along` where a single turn takes up all of `depth`, and whether
+/// that's all of them or only the first few
+fn stops(along: i128, depth: i128, max: i128) -> (Vec<i128>, bool) {
+    let size = depth.abs();
+    // stops from size / max out to along, or turns from size / along up to max
+    let over = |by: i128| (size as u128).div_ceil(by as u128) as i128;
+    let (nearest, furthest) = (over(max), along);
+    let (least, most) = (over(along.max(1)), max);
+    if depth == 0 || nearest > furthest {
+        return (Vec::new(), true);
+    }
+    let found = if furthest - nearest <= most - least {
+        (nearest..=furthest.min(nearest + FINISHES)).filter(|stop| size % stop == 0).collect()
+    } else {
+        (least..=most.min(least + FINISHES)).filter(|turn| size % turn == 0).map(|turn| size / turn).collect()
+    };
+    (found, (furthest - nearest).min(most - least) <= FINISHES)
+}
+
+/// The commands to head in from `along`, turning once at `stop`
+fn finishing(along: i128, stop: i128, max: i128) -> usize {
+    1 + cost(along - stop, max) + cost(stop, max)
+}
+
+/// The fewest commands found to head in from here turning at most once, and where to turn
+fn finish(along: i128, depth: i128, max: i128) -> Option<(usize, i128)> {
+    if depth == 0 {
+        return Some((cost(along, max), along));
+    }
+    stops(along, depth, max).0.into_iter().map(|stop| (finishing(along, stop, max), stop)).min()
+}
+
+/// An aim course to `(x, depth)` shorter than `limit` commands, if the search finds one within
+/// its budget
+fn search(x: i128, depth: i128, max: i128, limit: usize) -> Option<Vec<Command>> {
+    let side = if x < 0 { -1 } else { 1 };
+    let start = Node { along: x.abs(), depth, outward: true };
+    // the fewest commands found to each node, and the node and command that got there
+    let mut seen: HashMap<Node, (usize, Option<(Node, Command)>)> = HashMap::new();
+    // nodes to expand, by the fewest commands a course through them could take
+    let mut open: Vec<Vec<(Node, usize)>> = Vec::new();
+    let bound = start.bound(max).filter(|&bound| bound < limit)?;
+    seen.insert(start, (0, None));
+    open.resize_with(bound + 1, Vec::new);
+    open[bound].push((start, 0));
+    let mut expanded = 0;
+    let mut least = 0;
+    while least < open.len() {
+        let Some((node, taken)) = open[least].pop() else {
+            least += 1;
+            continue;
+        };
+        if seen[&node].0 != taken {
+            continue;
+        }
+        if let Some((left, stop)) = finish(node.along, node.depth, max).filter(|&(left, _)| taken + left == least) {
+            let mut course = Vec::with_capacity(taken + left);
+            let mut at = node;
+            while let (_, Some((from, command))) = seen[&at] {
+                course.push(command);
+                at = from;
+            }
+            course.reverse();
+            cover(&mut course, side * (node.along - stop), max, Command::Forward, Command::Back);
+            if node.depth != 0 {
+                let aim = node.depth / (side * stop);
+                cover(&mut course, aim, max, Command::Down, Command::Up);
+            }
+            cover(&mut course, side * stop, max, Command::Forward, Command::Back);
+            return Some(course);
+        }
+        expanded += 1;
+        if expanded > BUDGET {
+            return None;
+        }
+        for (next, command) in node.next(side, max) {
+            let taken = taken + 1;
+            if seen.get(&next).is_some_and(|&(fewest, _)| fewest <= taken) {
+                continue;
+            }
+            let Some(bound) = next.bound(max) else { continue };
+            // a node can't promise less than the one it came from
+            let through = taken.saturating_add(bound).max(least);
+            if through < limit {
+                seen.insert(next, (taken, Some((node, command))));
+                if open.len() <= through {
+                    open.resize_with(through + 1, Vec::new);
+                }
+                open[through].push((next, taken));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,185 @@
+//! The extended command language: every command verb, `repeat <n> { ... }` blocks, `#` comments
+//! and blank lines. A plain puzzle input is a program of one command per line.
+//!
+//! ```text
+//! # dive, then patrol
+//! down 5
+//! repeat 3 {
+//!     forward 10
+//!     hold 4
+//! }
+//! surface
+//! ```
+
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::Read;
+use std::str::FromStr;
+use parsing::{Line, ParseError};
+use crate::Command;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+    Command(Command),
+    Repeat { count: usize, body: Vec<Statement> },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    pub statements: Vec<Statement>,
+}
+
+impl Program {
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// Reads the whole program from any reader, such as stdin
+    pub fn from_reader<R: Read>(mut reader: R) -> anyhow::Result<Self> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        Ok(Self::from_str(&data)?)
+    }
+
+    /// Parses a program from any reader and runs through its commands as they're needed, holding
+    /// only the line being read and any `repeat` block still open
+    pub fn stream<R: Read>(reader: R) -> impl Iterator<Item = anyhow::Result<Command>> {
+        let mut lines = parsing::read_lines(reader);
+        let mut parser = Parser::default();
+        let mut pending: Box<dyn Iterator<Item = Command>> = Box::new(std::iter::empty());
+        std::iter::from_fn(move || loop {
+            if let Some(command) = pending.next() {
+                return Some(Ok(command));
+            }
+            let statement = match lines.next() {
+                Some(line) => match line {
+                    Ok(line) => parser.line(&line.line()),
+                    Err(e) => return Some(Err(e.into())),
+                },
+                None => return parser.finish().err().map(|e| Err(e.into())),
+            };
+            match statement {
+                Ok(statement) => pending = Box::new(statement.into_iter().flat_map(unroll)),
+                Err(e) => return Some(Err(e.into())),
+            }
+        })
+    }
+
+    /// The commands in the order they run, with repeats unrolled as they go rather than up front
+    pub fn commands(&self) -> impl Iterator<Item = Command> + '_ {
+        expand(&self.statements)
+    }
+}
+
+fn expand(statements: &[Statement]) -> Box<dyn Iterator<Item = Command> + '_> {
+    Box::new(statements.iter().flat_map(|statement| -> Box<dyn Iterator<Item = Command> + '_> {
+        match statement {
+            Statement::Command(command) => Box::new(std::iter::once(*command)),
+            Statement::Repeat { count, body } => Box::new((0..*count).flat_map(move |_| expand(body))),
+        }
+    }))
+}
+
+/// Like [`expand`] for a statement the iterator owns, copying a repeated body for each pass
+fn unroll(statement: Statement) -> Box<dyn Iterator<Item = Command>> {
+    match statement {
+        Statement::Command(command) => Box::new(std::iter::once(command)),
+        Statement::Repeat { count, body } => {
+            Box::new((0..count).flat_map(move |_| body.clone().into_iter().flat_map(unroll)))
+        }
+    }
+}
+
+impl From<Vec<Command>> for Program {
+    fn from(commands: Vec<Command>) -> Self {
+        Program { statements: commands.into_iter().map(Statement::Command).collect() }
+    }
+}
+
+/// An open `repeat` block, and the error to give if it's never closed
+struct Block {
+    count: usize,
+    body: Vec<Statement>,
+    unclosed: ParseError,
+}
+
+/// Reads a program a line at a time, handing back each top-level statement once it's complete
+#[derive(Default)]
+struct Parser {
+    blocks: Vec<Block>,
+}
+
+impl Parser {
+    fn line(&mut self, line: &Line) -> Result<Option<Statement>, ParseError> {
+        // drop comments, keeping the columns of what's left
+        let line = Line::new(line.number, line.text.split('#').next().unwrap_or_default());
+        let mut tokens = line.tokens();
+        let Some(first) = tokens.next() else { return Ok(None) };
+        let statement = match first.text {
+            "repeat" => {
+                let count = tokens.expect("a repeat count")?.parse::<usize>()?;
+                tokens.expect("`{`")?.expect("{")?;
+                tokens.finish()?;
+                let unclosed = line.error("`repeat` block is never closed");
+                self.blocks.push(Block { count, body: Vec::new(), unclosed });
+                return Ok(None);
+            }
+            "}" => {
+                tokens.finish()?;
+                let block = self.blocks.pop().ok_or_else(|| first.error("no block to close"))?;
+                Statement::Repeat { count: block.count, body: block.body }
+            }
+            _ => Statement::Command(Command::parse_line(&line)?),
+        };
+        match self.blocks.last_mut() {
+            Some(block) => {
+                block.body.push(statement);
+                Ok(None)
+            }
+            None => Ok(Some(statement)),
+        }
+    }
+
+    /// Checks every block was closed at the end of the input
+    fn finish(&mut self) -> Result<(), ParseError> {
+        match std::mem::take(&mut self.blocks).pop() {
+            Some(block) => Err(block.unclosed),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::default();
+        let mut statements = Vec::new();
+        for line in parsing::lines(s) {
+            statements.extend(parser.line(&line)?);
+        }
+        parser.finish()?;
+        Ok(Program { statements })
+    }
+}
+
+fn write_statements(f: &mut fmt::Formatter<'_>, statements: &[Statement], depth: usize) -> fmt::Result {
+    let indent = "    ".repeat(depth);
+    for statement in statements {
+        match statement {
+            Statement::Command(command) => writeln!(f, "{}{}", indent, command)?,
+            Statement::Repeat { count, body } => {
+                writeln!(f, "{}repeat {} {{", indent, count)?;
+                write_statements(f, body, depth + 1)?;
+                writeln!(f, "{}}}", indent)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_statements(f, &self.statements, 0)
+    }
+}
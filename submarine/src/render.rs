@@ -0,0 +1,140 @@
+//! Pictures of a dive: depth going down the page against horizontal position going across it.
+
+use std::fmt::Write;
+use crate::Trace;
+
+/// The extent of a dive, always including the surface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    min_x: isize,
+    max_x: isize,
+    min_depth: isize,
+    max_depth: isize,
+}
+
+impl Bounds {
+    fn of(points: &[(isize, isize)]) -> Self {
+        let mut bounds = Bounds { min_x: 0, max_x: 0, min_depth: 0, max_depth: 0 };
+        for &(x, depth) in points {
+            bounds.min_x = bounds.min_x.min(x);
+            bounds.max_x = bounds.max_x.max(x);
+            bounds.min_depth = bounds.min_depth.min(depth);
+            bounds.max_depth = bounds.max_depth.max(depth);
+        }
+        bounds
+    }
+}
+
+/// Maps `value` from `min..=max` onto `0..=steps`, in wide integers so huge dives don't overflow
+fn scale(value: isize, min: isize, max: isize, steps: usize) -> usize {
+    let span = (max as i128 - min as i128).max(1);
+    ((value as i128 - min as i128) * steps as i128 / span) as usize
+}
+
+fn points(trace: &Trace) -> Vec<(isize, isize)> {
+    trace.states().map(|state| (state.x, state.depth)).collect()
+}
+
+/// Every cell on the line between two cells, ends included
+fn line((x0, y0): (usize, usize), (x1, y1): (usize, usize)) -> Vec<(usize, usize)> {
+    let (x0, y0, x1, y1) = (x0 as isize, y0 as isize, x1 as isize, y1 as isize);
+    let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+    let (sx, sy) = (if x0 < x1 { 1 } else { -1 }, if y0 < y1 { 1 } else { -1 });
+    let (mut x, mut y, mut err) = (x0, y0, dx + dy);
+    let mut cells = vec![(x as usize, y as usize)];
+    while (x, y) != (x1, y1) {
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+        cells.push((x as usize, y as usize));
+    }
+    cells
+}
+
+/// A `width` by `height` character plot of the dive, with the surface drawn as `~` and the depth
+/// and horizontal ranges labelled
+pub fn ascii(trace: &Trace, width: usize, height: usize) -> String {
+    let (width, height) = (width.max(2), height.max(2));
+    let points = points(trace);
+    let bounds = Bounds::of(&points);
+    let cell = |(x, depth): (isize, isize)| {
+        let col = scale(x, bounds.min_x, bounds.max_x, width - 1);
+        (col, scale(depth, bounds.min_depth, bounds.max_depth, height - 1))
+    };
+    let mut grid = vec![vec![' '; width]; height];
+    let surface = cell((bounds.min_x, 0)).1;
+    grid[surface].fill('~');
+    for pair in points.windows(2) {
+        for (col, row) in line(cell(pair[0]), cell(pair[1])) {
+            grid[row][col] = '*';
+        }
+    }
+    if let Some(&start) = points.first() {
+        let (col, row) = cell(start);
+        grid[row][col] = 'o';
+    }
+
+    let top = format!("depth {}", bounds.min_depth);
+    let bottom = format!("depth {}", bounds.max_depth);
+    let margin = top.len().max(bottom.len());
+    let mut out = String::new();
+    for (i, row) in grid.iter().enumerate() {
+        let label = match i {
+            0 => top.as_str(),
+            i if i == height - 1 => bottom.as_str(),
+            _ => "",
+        };
+        writeln!(out, "{:>margin$} |{}", label, row.iter().collect::<String>()).unwrap();
+    }
+    writeln!(out, "{:>margin$} +{}", "", "-".repeat(width)).unwrap();
+    let left = format!("x {}", bounds.min_x);
+    let right = bounds.max_x.to_string();
+    let gap = (width + 1).saturating_sub(left.len() + right.len()).max(1);
+    writeln!(out, "{:>margin$} {}{}{}", "", left, " ".repeat(gap), right).unwrap();
+    out
+}
+
+/// An SVG image of the dive, `width` by `height` pixels
+pub fn svg(trace: &Trace, width: usize, height: usize) -> String {
+    const PAD: usize = 40;
+    let (width, height) = (width.max(2 * PAD + 1), height.max(2 * PAD + 1));
+    let points = points(trace);
+    let bounds = Bounds::of(&points);
+    let (plot_width, plot_height) = (width - 2 * PAD, height - 2 * PAD);
+    let at = |(x, depth): (isize, isize)| {
+        let x = PAD + scale(x, bounds.min_x, bounds.max_x, plot_width);
+        (x, PAD + scale(depth, bounds.min_depth, bounds.max_depth, plot_height))
+    };
+    let surface = at((bounds.min_x, 0)).1;
+    let path: Vec<String> = points.iter().map(|&p| {
+        let (x, y) = at(p);
+        format!("{},{}", x, y)
+    }).collect();
+
+    let mut out = String::new();
+    let (w, h) = (width, height);
+    writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#).unwrap();
+    writeln!(out, r#"  <rect width="{w}" height="{h}" fill="white"/>"#).unwrap();
+    // the water, from the surface down
+    let water = height - PAD - surface;
+    writeln!(out, r#"  <rect x="{PAD}" y="{surface}" width="{plot_width}" height="{water}" fill="aliceblue"/>"#).unwrap();
+    let right = width - PAD;
+    writeln!(out, r#"  <line x1="{PAD}" y1="{surface}" x2="{right}" y2="{surface}" stroke="steelblue"/>"#).unwrap();
+    let path = path.join(" ");
+    writeln!(out, r#"  <polyline points="{path}" fill="none" stroke="black" stroke-width="1.5"/>"#).unwrap();
+    let text = |out: &mut String, x: usize, y: usize, anchor: &str, label: String| {
+        let font = r#"font-family="monospace" font-size="12""#;
+        writeln!(out, r#"  <text x="{x}" y="{y}" {font} text-anchor="{anchor}">{label}</text>"#).unwrap();
+    };
+    text(&mut out, PAD, PAD - 8, "start", format!("depth {}", bounds.min_depth));
+    text(&mut out, PAD, height - PAD + 16, "start", format!("depth {}, x {}", bounds.max_depth, bounds.min_x));
+    text(&mut out, width - PAD, height - PAD + 16, "end", format!("x {}", bounds.max_x));
+    writeln!(out, "</svg>").unwrap();
+    out
+}
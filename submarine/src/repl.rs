@@ -0,0 +1,159 @@
+//! Driving the submarine one line at a time.
+//!
+//! Each line is a command such as `forward 5`, or one of the session's own:
+//!
+//! - `state` shows where the submarine is
+//! - `undo` takes back the last command or load
+//! - `load <file>` follows every command in a file
+//! - `save <file>` writes every command followed so far, one per line
+//! - `reset` goes back to the surface
+//! - `help` lists these, and `quit` ends the session
+//!
+//! Undoing puts the submarine back at the surface and follows the remaining commands again, so
+//! constraints and clamping come out just as they did the first time.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use anyhow::{Result, anyhow};
+use crate::{Command, State, Sub, Violation};
+
+const HELP: &str = "\
+commands:   forward, down, up, back, hold <distance>, surface
+            port, starboard <quarter turns>, for the 3d models
+state       show where the submarine is
+undo        take back the last command or load
+load FILE   follow every command in a file
+save FILE   write the commands followed so far
+reset       go back to the surface
+quit        end the session";
+
+/// What a line did, for showing to the user
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// The submarine moved or was asked where it is
+    State { state: State, followed: usize, violations: Vec<Violation> },
+    /// Something to tell the user
+    Message(String),
+    Quit,
+}
+
+/// A submarine and what it has been told to do
+pub struct Session {
+    sub: Sub,
+    /// The commands followed, grouped by the line that gave them so `undo` takes back a whole load
+    history: Vec<Vec<Command>>,
+}
+
+impl Session {
+    pub fn new(sub: Sub) -> Self {
+        Session { sub, history: Vec::new() }
+    }
+
+    pub fn sub(&self) -> &Sub {
+        &self.sub
+    }
+
+    /// Every command followed so far
+    pub fn commands(&self) -> impl Iterator<Item = Command> + '_ {
+        self.history.iter().flatten().copied()
+    }
+
+    /// Carries out one line. Lines that can't be carried out, including commands that break a
+    /// constraint in error mode, leave the submarine where it was.
+    pub fn execute(&mut self, line: &str) -> Result<Reply> {
+        let line = line.trim();
+        let (word, arg) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let arg = arg.trim();
+        match word {
+            "" | "state" => Ok(self.state(Vec::new())),
+            "help" => Ok(Reply::Message(HELP.to_string())),
+            "quit" | "exit" => Ok(Reply::Quit),
+            "undo" => {
+                if self.history.pop().is_none() {
+                    return Ok(Reply::Message("nothing to undo".to_string()));
+                }
+                self.replay()?;
+                Ok(self.state(Vec::new()))
+            }
+            "reset" => {
+                self.history.clear();
+                self.sub.reset();
+                Ok(self.state(Vec::new()))
+            }
+            "load" => {
+                let commands = Command::from_file(path(arg)?)?;
+                self.follow(commands)
+            }
+            "save" => {
+                let mut file = io::BufWriter::new(fs::File::create(path(arg)?)?);
+                for command in self.commands() {
+                    writeln!(file, "{}", command)?;
+                }
+                file.flush()?;
+                Ok(Reply::Message(format!("saved {} commands to {}", self.followed(), arg)))
+            }
+            _ => self.follow(vec![Command::from_str(line)?]),
+        }
+    }
+
+    fn follow(&mut self, commands: Vec<Command>) -> Result<Reply> {
+        match self.sub.run_commands(&commands) {
+            Ok(violations) => {
+                self.history.push(commands);
+                Ok(self.state(violations))
+            }
+            Err(violation) => {
+                // a load can stop part way, so go back to where the line started
+                self.replay()?;
+                Err(violation.into())
+            }
+        }
+    }
+
+    /// Follows the history again from the surface
+    fn replay(&mut self) -> Result<()> {
+        self.sub.reset();
+        let commands: Vec<Command> = self.commands().collect();
+        self.sub.run_commands(&commands)?;
+        Ok(())
+    }
+
+    /// How many commands have been followed
+    pub fn followed(&self) -> usize {
+        self.history.iter().map(Vec::len).sum()
+    }
+
+    fn state(&self, violations: Vec<Violation>) -> Reply {
+        Reply::State { state: self.sub.state, followed: self.followed(), violations }
+    }
+}
+
+fn path(arg: &str) -> Result<&str> {
+    if arg.is_empty() { Err(anyhow!("expected a file name")) } else { Ok(arg) }
+}
+
+/// Reads lines from `input` until it ends or the user quits, writing a prompt before each line
+/// and the reply after it. Mistakes are reported and the session carries on.
+pub fn run<R: BufRead, W: Write>(session: &mut Session, input: R, mut output: W) -> io::Result<()> {
+    let mut lines = input.lines();
+    loop {
+        write!(output, "> ")?;
+        output.flush()?;
+        let Some(line) = lines.next() else {
+            writeln!(output)?;
+            return Ok(());
+        };
+        match session.execute(&line?) {
+            Ok(Reply::State { state, followed, violations }) => {
+                for violation in violations {
+                    writeln!(output, "warning: {}", violation)?;
+                }
+                writeln!(output, "{} after {} commands", state, followed)?;
+            }
+            Ok(Reply::Message(message)) => writeln!(output, "{}", message)?,
+            Ok(Reply::Quit) => return Ok(()),
+            Err(e) => writeln!(output, "error: {}", e)?,
+        }
+    }
+}
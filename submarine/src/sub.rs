@@ -0,0 +1,123 @@
+use crate::{Breach, Command, Effect, Constraints, Mode, Program, State, SteeringModel, Trace, Violation};
+
+/// A submarine that moves by its steering model, within its constraints
+pub struct Sub {
+    pub state: State,
+    pub constraints: Constraints,
+    model: Box<dyn SteeringModel>,
+    trace: Option<Trace>,
+    /// How many commands have been followed, to index violations by
+    followed: usize,
+}
+
+impl Sub {
+    /// A submarine at the surface, free to go anywhere its position doesn't overflow
+    pub fn new(model: Box<dyn SteeringModel>) -> Self {
+        Sub { state: State::default(), constraints: Constraints::default(), model, trace: None, followed: 0 }
+    }
+
+    pub fn with_constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Records every state from here on, starting with the current one
+    pub fn record(&mut self) {
+        self.trace = Some(Trace::new(self.state));
+    }
+
+    /// The states recorded since [`Sub::record`] was called
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
+    /// Stops recording and hands back what was recorded
+    pub fn take_trace(&mut self) -> Option<Trace> {
+        self.trace.take()
+    }
+
+    /// Puts the submarine back at the surface as if it were new, keeping its model and
+    /// constraints. A trace being recorded starts over.
+    pub fn reset(&mut self) {
+        self.state = State::default();
+        self.followed = 0;
+        if self.trace.is_some() {
+            self.record();
+        }
+    }
+
+    pub fn model(&self) -> &dyn SteeringModel {
+        self.model.as_ref()
+    }
+
+    /// Follows one command, returning the constraints it broke. In error mode the first broken
+    /// constraint is returned as an error instead, and the command is not followed.
+    pub fn step(&mut self, command: Command) -> Result<Vec<Violation>, Violation> {
+        let index = self.followed;
+        let violation = |breach| Violation { index, command, breach };
+        let (breaches, next) = match self.model.step(self.state, command) {
+            Some(next) => {
+                let (breaches, clamped) = self.constraints.check(next);
+                let next = if self.constraints.mode == Mode::Clamp { clamped } else { next };
+                (breaches, next)
+            }
+            None => (vec![Breach::Overflow], self.state),
+        };
+        if let (Mode::Error, Some(&breach)) = (self.constraints.mode, breaches.first()) {
+            return Err(violation(breach));
+        }
+        self.followed += 1;
+        self.state = next;
+        if let Some(trace) = &mut self.trace {
+            trace.push(command, self.state);
+        }
+        Ok(breaches.into_iter().map(violation).collect())
+    }
+
+    /// Follows every command, collecting the constraints they broke. In error mode this stops at
+    /// the first violation.
+    pub fn run_commands(&mut self, commands: &[Command]) -> Result<Vec<Violation>, Violation> {
+        self.run(commands.iter().copied())
+    }
+
+    pub fn run_program(&mut self, program: &Program) -> Result<Vec<Violation>, Violation> {
+        self.run(program.commands())
+    }
+
+    /// Follows commands as they arrive, such as from [`Command::stream`] or a
+    /// [`binary::Decoder`](crate::binary::Decoder), passing each violation
+    /// to `on_violation` rather than collecting them. Unless a trace is being recorded this runs in
+    /// constant memory however long the course is. Returns how many commands were followed.
+    pub fn run_stream<I, E>(&mut self, commands: I, mut on_violation: impl FnMut(Violation)) -> anyhow::Result<usize>
+    where
+        I: IntoIterator<Item = Result<Command, E>>,
+        E: Into<anyhow::Error>,
+    {
+        let mut followed = 0;
+        for command in commands {
+            for violation in self.step(command.map_err(Into::into)?)? {
+                on_violation(violation);
+            }
+            followed += 1;
+        }
+        Ok(followed)
+    }
+
+    /// Follows every command by combining their [`Effect`]s in parallel, ending where
+    /// [`Sub::run_commands`] would. Constraints are not checked and nothing is traced along the way.
+    /// Returns `None`, leaving the submarine where it was, if the model has no effects or the
+    /// position overflows.
+    pub fn run_parallel(&mut self, commands: &[Command]) -> Option<State> {
+        self.state = Effect::par_of(self.model(), commands)?.apply(self.state)?;
+        self.followed += commands.len();
+        Some(self.state)
+    }
+
+    fn run(&mut self, commands: impl Iterator<Item = Command>) -> Result<Vec<Violation>, Violation> {
+        let mut violations = Vec::new();
+        for command in commands {
+            violations.extend(self.step(command)?);
+        }
+        Ok(violations)
+    }
+}
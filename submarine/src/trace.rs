@@ -0,0 +1,81 @@
+use std::io::{self, Write};
+use crate::{Command, State, SteeringModel};
+
+/// The state after one command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// The command's position in the stream, from 0
+    pub index: usize,
+    pub command: Command,
+    pub state: State,
+}
+
+/// Every state a submarine went through, for looking back over a dive
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub start: State,
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn new(start: State) -> Self {
+        Trace { start, steps: Vec::new() }
+    }
+
+    pub fn push(&mut self, command: Command, state: State) {
+        self.steps.push(Step { index: self.steps.len(), command, state });
+    }
+
+    /// The starting state followed by the state after each command
+    pub fn states(&self) -> impl Iterator<Item = State> + '_ {
+        std::iter::once(self.start).chain(self.steps.iter().map(|step| step.state))
+    }
+
+    /// The commands that were followed, for replaying them under another model
+    pub fn commands(&self) -> impl Iterator<Item = Command> + '_ {
+        self.steps.iter().map(|step| step.command)
+    }
+
+    /// Follows the same commands from the same start under another model, or `None` if the
+    /// position overflows
+    pub fn replay(&self, model: &dyn SteeringModel) -> Option<Trace> {
+        let mut trace = Trace::new(self.start);
+        let mut state = self.start;
+        for command in self.commands() {
+            state = model.step(state, command)?;
+            trace.push(command, state);
+        }
+        Some(trace)
+    }
+
+    /// The first step that reached the deepest point of the dive
+    pub fn max_depth(&self) -> Option<&Step> {
+        self.steps.iter().reduce(|deepest, step| {
+            if step.state.depth > deepest.state.depth { step } else { deepest }
+        })
+    }
+
+    /// The first step that took the submarine from above `depth` to at or below it
+    pub fn first_crossing(&self, depth: isize) -> Option<&Step> {
+        self.steps.iter()
+            .zip(self.states())
+            .find(|(step, before)| before.depth < depth && step.state.depth >= depth)
+            .map(|(step, _)| step)
+    }
+
+    /// How many steps ended at or above the surface
+    pub fn time_at_surface(&self) -> usize {
+        self.steps.iter().filter(|step| step.state.depth <= 0).count()
+    }
+
+    pub const CSV_HEADER: &'static str = "index,command,x,depth,aim";
+
+    /// Writes a row per step, after a header
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", Self::CSV_HEADER)?;
+        for Step { index, command, state } in &self.steps {
+            writeln!(writer, "{},{},{},{},{}", index, command, state.x, state.depth, state.aim)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use proptest::prelude::*;
+use submarine::binary::{self, Decoder};
+use submarine::{Aim, Command, Sub};
+
+const SAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+fn command() -> impl Strategy<Value = Command> {
+    prop_oneof![
+        any::<isize>().prop_map(Command::Forward),
+        any::<isize>().prop_map(Command::Down),
+        any::<isize>().prop_map(Command::Up),
+        any::<isize>().prop_map(Command::Back),
+        Just(Command::Surface),
+        any::<isize>().prop_map(Command::Hold),
+        any::<isize>().prop_map(Command::Port),
+        any::<isize>().prop_map(Command::Starboard),
+    ]
+}
+
+#[test]
+fn small_commands_take_two_bytes() {
+    let bytes = binary::encode(&[Command::Forward(5), Command::Up(-3), Command::Surface, Command::Down(64)]);
+    assert_eq!(bytes, b"SUBC\x01\x00\x0a\x02\x05\x04\x01\x80\x01");
+}
+
+#[test]
+fn subs_run_straight_from_the_binary_format() {
+    let commands = Command::from_lines(SAMPLE).unwrap();
+    let bytes = binary::encode(&commands);
+    let mut sub = Sub::new(Box::new(Aim));
+    let followed = sub.run_stream(Decoder::new(&bytes[..]).unwrap(), |_| ()).unwrap();
+    assert_eq!(followed, 6);
+    assert_eq!(sub.state.agg(), 900);
+}
+
+#[test]
+fn bad_streams_are_reported() {
+    let error = |bytes: &[u8]| binary::decode(bytes).unwrap_err().to_string();
+    assert_eq!(error(b"forward 5\n"), "not a binary course, it doesn't start with SUBC");
+    assert_eq!(error(b"SUBC\x02"), "binary course version 2 isn't supported, only 1");
+    assert_eq!(error(b"SUBC\x01\x04\x09"), "byte 6: unknown opcode 9");
+    assert_eq!(error(b"SUBC\x01\x00\x85"), "byte 6: course ends inside a distance");
+    assert_eq!(error(b"SUBC\x01\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\x7f"), "byte 6: distance is longer than 64 bits");
+    assert!(binary::decode(b"SUBC\x01").unwrap().is_empty());
+}
+
+proptest! {
+    #[test]
+    fn courses_survive_encoding(commands in prop::collection::vec(command(), 0..100)) {
+        let bytes = binary::encode(&commands);
+        prop_assert_eq!(binary::decode(&bytes).unwrap(), commands);
+    }
+
+    #[test]
+    fn decoding_arbitrary_bytes_never_panics(bytes in any::<Vec<u8>>()) {
+        let mut stream = b"SUBC\x01".to_vec();
+        stream.extend(&bytes);
+        let _ = binary::decode(&stream);
+        let _ = binary::decode(&bytes);
+    }
+}
@@ -0,0 +1,64 @@
+use submarine::{Aim, Breach, Command, Constraints, Direct, Mode, State, Sub};
+
+/// Dives to 10 and then tries to fly out of the water
+const COURSE: &str = "down 10\nforward 3\nup 15\nforward 1\n";
+
+fn run(mode: Mode) -> (Sub, Result<Vec<submarine::Violation>, submarine::Violation>) {
+    let constraints = Constraints { min_depth: Some(0), max_depth: Some(8), max_aim: None, mode };
+    let mut sub = Sub::new(Box::new(Direct)).with_constraints(constraints);
+    let result = sub.run_commands(&Command::from_lines(COURSE).unwrap());
+    (sub, result)
+}
+
+#[test]
+fn warn_mode_reports_every_violation() {
+    let (sub, result) = run(Mode::Warn);
+    let violations = result.unwrap();
+    let breaches: Vec<(usize, Breach)> = violations.iter().map(|v| (v.index, v.breach)).collect();
+    assert_eq!(breaches, [
+        (0, Breach::TooDeep { depth: 10, max: 8 }),
+        (1, Breach::TooDeep { depth: 10, max: 8 }),
+        (2, Breach::TooShallow { depth: -5, min: 0 }),
+        (3, Breach::TooShallow { depth: -5, min: 0 }),
+    ]);
+    assert_eq!(sub.state, State::new(4, -5, 0));
+}
+
+#[test]
+fn clamp_mode_keeps_the_sub_inside_the_limits() {
+    let (sub, result) = run(Mode::Clamp);
+    assert_eq!(result.unwrap().iter().map(|v| v.index).collect::<Vec<_>>(), [0, 2]);
+    assert_eq!(sub.state, State::new(4, 0, 0));
+}
+
+#[test]
+fn error_mode_stops_before_the_first_violation() {
+    let (sub, result) = run(Mode::Error);
+    let violation = result.unwrap_err();
+    assert_eq!(violation.index, 0);
+    assert_eq!(violation.command, Command::Down(10));
+    assert_eq!(sub.state, State::default());
+}
+
+#[test]
+fn aim_is_limited_both_ways() {
+    let constraints = Constraints { max_aim: Some(3), mode: Mode::Clamp, ..Constraints::default() };
+    let mut sub = Sub::new(Box::new(Aim)).with_constraints(constraints);
+    sub.run_commands(&[Command::Down(5)]).unwrap();
+    assert_eq!(sub.state.aim, 3);
+    sub.run_commands(&[Command::Up(10)]).unwrap();
+    assert_eq!(sub.state.aim, -3);
+}
+
+#[test]
+fn overflow_is_always_a_violation() {
+    let commands = [Command::Down(isize::MAX), Command::Forward(2)];
+    let mut sub = Sub::new(Box::new(Aim));
+    assert_eq!(sub.run_commands(&commands).unwrap_err().breach, Breach::Overflow);
+
+    let mut sub = Sub::new(Box::new(Aim)).with_constraints(Constraints { mode: Mode::Clamp, ..Constraints::default() });
+    let violations = sub.run_commands(&commands).unwrap();
+    assert_eq!(violations.len(), 1);
+    // there is nothing to clamp an overflow to, so the command is skipped
+    assert_eq!(sub.state, State::new(0, 0, isize::MAX));
+}
@@ -0,0 +1,107 @@
+use proptest::prelude::*;
+use submarine::diff::{self, Edit};
+use submarine::{Aim, Command, Direct, State, Sub};
+
+const SAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+fn course(s: &str) -> Vec<Command> {
+    Command::from_lines(s).unwrap()
+}
+
+#[test]
+fn same_courses_never_diverge() {
+    let sample = course(SAMPLE);
+    let comparison = diff::compare(&mut Sub::new(Box::new(Aim)), &sample, &mut Sub::new(Box::new(Aim)), &sample);
+    let comparison = comparison.unwrap();
+    assert!(comparison.divergence.is_none());
+    assert!(comparison.edits.iter().all(|edit| matches!(edit, Edit::Same(_))));
+    assert_eq!(comparison.change(), [0; 4]);
+}
+
+#[test]
+fn finds_the_first_changed_command() {
+    let left = course(SAMPLE);
+    let right = course("forward 5\ndown 5\nforward 8\nup 2\ndown 8\nforward 2\n");
+    let comparison = diff::compare(&mut Sub::new(Box::new(Aim)), &left, &mut Sub::new(Box::new(Aim)), &right);
+    let comparison = comparison.unwrap();
+    let divergence = comparison.divergence.unwrap();
+    assert_eq!(divergence.index, 3);
+    assert_eq!((divergence.left, divergence.right), (Some(Command::Up(3)), Some(Command::Up(2))));
+    assert_eq!(divergence.state, State::new(13, 40, 5));
+    assert_eq!(comparison.left.agg(), 900);
+    assert_eq!(comparison.right, State::new(15, 62, 11));
+    assert_eq!(comparison.change(), [0, 2, 1, 30]);
+}
+
+#[test]
+fn lines_up_insertions_and_deletions() {
+    let left = course("forward 1\ndown 2\nforward 3\nup 4\n");
+    let right = course("down 2\nforward 3\nhold 9\nup 4\n");
+    let edits = diff::align(&left, &right);
+    assert_eq!(
+        edits,
+        [
+            Edit::Removed(Command::Forward(1)),
+            Edit::Same(Command::Down(2)),
+            Edit::Same(Command::Forward(3)),
+            Edit::Added(Command::Hold(9)),
+            Edit::Same(Command::Up(4)),
+        ]
+    );
+    let comparison = diff::compare(&mut Sub::new(Box::new(Direct)), &left, &mut Sub::new(Box::new(Direct)), &right);
+    let divergence = comparison.unwrap().divergence.unwrap();
+    assert_eq!((divergence.index, divergence.left, divergence.right), (0, Some(Command::Forward(1)), Some(Command::Down(2))));
+}
+
+#[test]
+fn long_courses_with_few_changes_align_quickly() {
+    let left: Vec<Command> = (0..100_000).map(|i| Command::Forward(i % 7 + 1)).collect();
+    let mut right = left.clone();
+    for i in (500..right.len()).step_by(1000) {
+        right[i] = Command::Hold(9);
+    }
+    right.insert(20_000, Command::Down(3));
+    right.remove(70_000);
+    let edits = diff::align(&left, &right);
+    assert_eq!(common(&edits), left.len() - 101);
+    assert_eq!(edits.len(), left.len() + 101);
+}
+
+fn command() -> impl Strategy<Value = Command> {
+    prop_oneof![(1..4isize).prop_map(Command::Forward), (1..4isize).prop_map(Command::Down)]
+}
+
+fn common(edits: &[Edit]) -> usize {
+    edits.iter().filter(|edit| matches!(edit, Edit::Same(_))).count()
+}
+
+/// The length of the longest common subsequence, the slow way
+fn longest_common(left: &[Command], right: &[Command]) -> usize {
+    let mut row = vec![0; right.len() + 1];
+    for l in left {
+        let mut diagonal = 0;
+        for (j, r) in right.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if l == r { diagonal + 1 } else { above.max(row[j]) };
+            diagonal = above;
+        }
+    }
+    row[right.len()]
+}
+
+proptest! {
+    #[test]
+    fn alignments_rebuild_both_courses(
+        left in prop::collection::vec(command(), 0..40),
+        right in prop::collection::vec(command(), 0..40),
+    ) {
+        let edits = diff::align(&left, &right);
+        let rebuilt_left: Vec<Command> =
+            edits.iter().filter(|e| !matches!(e, Edit::Added(_))).map(Edit::command).collect();
+        let rebuilt_right: Vec<Command> =
+            edits.iter().filter(|e| !matches!(e, Edit::Removed(_))).map(Edit::command).collect();
+        prop_assert_eq!(common(&edits), longest_common(&left, &right));
+        prop_assert_eq!(rebuilt_left, left);
+        prop_assert_eq!(rebuilt_right, right);
+    }
+}
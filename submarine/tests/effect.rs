@@ -0,0 +1,77 @@
+use proptest::prelude::*;
+use submarine::{model, Command, Constraints, Effect, Mode, State, Sub};
+
+fn command() -> impl Strategy<Value = Command> {
+    let d = -1000..1000isize;
+    prop_oneof![
+        d.clone().prop_map(Command::Forward),
+        d.clone().prop_map(Command::Down),
+        d.clone().prop_map(Command::Up),
+        d.clone().prop_map(Command::Back),
+        d.prop_map(Command::Hold),
+        Just(Command::Surface),
+        (-4..4isize).prop_map(Command::Port),
+        (-4..4isize).prop_map(Command::Starboard),
+    ]
+}
+
+/// The built in models that have effects; the ones that turn don't
+const AFFINE: [&str; 2] = ["direct", "aim"];
+
+fn sub(name: &str) -> Sub {
+    // the parallel path never checks constraints, so warn rather than stop the sequential one
+    let constraints = Constraints { mode: Mode::Warn, ..Constraints::default() };
+    Sub::new(model::builtin(name).unwrap()).with_constraints(constraints)
+}
+
+proptest! {
+    // long courses, so rayon has something to split
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn parallel_runs_match_sequential_runs(commands in prop::collection::vec(command(), 0..5000)) {
+        for name in AFFINE {
+            let mut sequential = sub(name);
+            sequential.run_commands(&commands).unwrap();
+            let mut parallel = sub(name);
+            prop_assert_eq!(parallel.run_parallel(&commands), Some(sequential.state));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn effects_compose_associatively(
+        a in prop::collection::vec(command(), 0..20),
+        b in prop::collection::vec(command(), 0..20),
+        c in prop::collection::vec(command(), 0..20),
+    ) {
+        for name in AFFINE {
+            let model = model::builtin(name).unwrap();
+            let [a, b, c] = [&a, &b, &c].map(|commands| Effect::of(model.as_ref(), commands).unwrap());
+            prop_assert_eq!(a.then(&b).unwrap().then(&c), b.then(&c).and_then(|bc| a.then(&bc)));
+        }
+    }
+
+    #[test]
+    fn effects_move_any_start_like_the_model(
+        x in -1000..1000isize,
+        depth in -1000..1000isize,
+        aim in -1000..1000isize,
+        command in command(),
+    ) {
+        let state = State::new(x, depth, aim);
+        for name in AFFINE {
+            let model = model::builtin(name).unwrap();
+            prop_assert_eq!(model.effect(command).unwrap().apply(state), model.step(state, command));
+        }
+    }
+}
+
+#[test]
+fn the_puzzle_answer_comes_out_of_a_parallel_run() {
+    let commands = Command::from_file(concat!(env!("CARGO_MANIFEST_DIR"), "/../2.2/input.txt")).unwrap();
+    let mut sub = Sub::new(model::builtin("aim").unwrap());
+    let state = sub.run_parallel(&commands).unwrap();
+    assert_eq!(state.x * state.depth, 1698850445);
+}
@@ -0,0 +1,97 @@
+use proptest::prelude::*;
+use submarine::{model, Aim, Command, Direct, Heading, Navigator, State, SteeringModel, Sub};
+
+const SAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+fn run(model: Box<dyn SteeringModel>) -> State {
+    let mut sub = Sub::new(model);
+    sub.run_commands(&Command::from_lines(SAMPLE).unwrap()).unwrap();
+    sub.state
+}
+
+/// Reads the commands as if the submarine were upside down
+struct Inverted;
+
+impl SteeringModel for Inverted {
+    fn name(&self) -> &str {
+        "inverted"
+    }
+
+    fn step(&self, state: State, command: Command) -> Option<State> {
+        let flipped = match command {
+            Command::Down(d) => Command::Up(d),
+            Command::Up(d) => Command::Down(d),
+            forward => forward,
+        };
+        Direct.step(state, flipped)
+    }
+}
+
+#[test]
+fn builtin_models_solve_the_sample() {
+    assert_eq!(run(Box::new(Direct)), State::new(15, 10, 0));
+    assert_eq!(run(Box::new(Aim)), State::new(15, 60, 10));
+}
+
+#[test]
+fn builtin_models_are_found_by_name() {
+    for name in model::BUILTIN {
+        assert_eq!(model::builtin(name).unwrap().name(), name);
+        assert_eq!(model::find(name).unwrap().name(), name);
+    }
+    assert!(model::builtin("inverted").is_none());
+    let err = model::find("inverted").err().unwrap().to_string();
+    assert_eq!(err, "unknown steering model inverted, expected one of: direct, aim, direct-3d, aim-3d");
+}
+
+#[test]
+fn user_defined_models_steer_the_sub() {
+    assert_eq!(run(Box::new(Inverted)), State::new(15, -10, 0));
+}
+
+#[test]
+fn navigators_turn_and_move_along_their_heading() {
+    let course = "down 2\nforward 3\nport 1\nforward 4\nstarboard 3\nforward 1\nup 2\nback 5\n";
+    let mut sub = Sub::new(Box::new(Navigator::new(Aim)));
+    sub.run_commands(&Command::from_lines(course).unwrap()).unwrap();
+    let end = State { lateral: 4, heading: Heading::West, ..State::new(7, 16, 0) };
+    assert_eq!(sub.state, end);
+    assert_eq!(sub.state.projection(), State::new(7, 16, 0));
+    assert_eq!(sub.state.to_string(), "x 7, depth 16, aim 0, lateral 4, heading west");
+    assert_eq!(Heading::East.turn(-1), Heading::South);
+    assert_eq!(Heading::South.turn(isize::MIN), Heading::South);
+}
+
+fn course() -> impl Strategy<Value = Vec<Command>> {
+    let command = prop_oneof![
+        (-50..50isize).prop_map(Command::Forward),
+        (-50..50isize).prop_map(Command::Down),
+        (-50..50isize).prop_map(Command::Up),
+        (-50..50isize).prop_map(Command::Back),
+        (-50..50isize).prop_map(Command::Hold),
+        Just(Command::Surface),
+        (-5..5isize).prop_map(Command::Port),
+        (-5..5isize).prop_map(Command::Starboard),
+    ];
+    prop::collection::vec(command, 0..60)
+}
+
+fn follow(model: &dyn SteeringModel, course: &[Command]) -> State {
+    course.iter().fold(State::default(), |state, &command| model.step(state, command).unwrap())
+}
+
+proptest! {
+    #[test]
+    fn navigators_seen_side_on_steer_like_their_model(course in course()) {
+        let straight: Vec<Command> =
+            course.iter().copied().filter(|c| !matches!(c, Command::Port(_) | Command::Starboard(_))).collect();
+        for (flat, solid) in [
+            (&Direct as &dyn SteeringModel, &Navigator::new(Direct) as &dyn SteeringModel),
+            (&Aim, &Navigator::new(Aim)),
+        ] {
+            prop_assert_eq!(follow(solid, &straight), follow(flat, &straight));
+            let (turning, level) = (follow(solid, &course), follow(flat, &course));
+            prop_assert_eq!((turning.depth, turning.aim), (level.depth, level.aim));
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use proptest::prelude::*;
+use submarine::optimize::optimize;
+use submarine::{model, Aim, Command, Direct, Navigator, State, SteeringModel, Sub};
+
+fn course(s: &str) -> Vec<Command> {
+    Command::from_lines(s).unwrap()
+}
+
+fn optimized(model: &dyn SteeringModel, s: &str) -> Vec<Command> {
+    optimize(model, &course(s))
+}
+
+#[test]
+fn merges_and_cancels_neighbours() {
+    assert_eq!(optimized(&Aim, "down 3\ndown 2\nup 1\n"), course("down 4\n"));
+    assert_eq!(optimized(&Aim, "forward 2\ndown 3\nup 3\nforward 4\n"), course("forward 6\n"));
+    assert_eq!(optimized(&Aim, "up 2\ndown 2\nforward 0\nhold 0\n"), []);
+    assert_eq!(optimized(&Aim, "forward 5\nback 7\n"), course("back 2\n"));
+}
+
+#[test]
+fn folds_aim_changes_past_commands_they_commute_with() {
+    assert_eq!(optimized(&Aim, "down 1\nhold 5\ndown 2\n"), course("down 3\nhold 5\n"));
+    assert_eq!(optimized(&Aim, "down 1\nforward 5\ndown 2\n"), course("down 1\nforward 5\ndown 2\n"));
+    // moving forward and then surfacing is the same as surfacing first
+    assert_eq!(optimized(&Aim, "down 4\nforward 1\nsurface\nsurface\n"), course("surface\nforward 1\n"));
+    assert_eq!(optimized(&Aim, "down 4\nup 9\nsurface\n"), course("surface\n"));
+}
+
+#[test]
+fn follows_the_rules_of_each_model() {
+    // under the direct model every move commutes except with surfacing
+    let sample = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+    assert_eq!(optimized(&Direct, sample), course("forward 15\ndown 10\n"));
+    assert_eq!(optimized(&Direct, "down 3\nsurface\nforward 1\n"), course("surface\nforward 1\n"));
+    assert_eq!(optimized(&Direct, "port 1\nforward 1\n"), course("forward 1\n"));
+    // turning models have no effects to check against
+    let turning = course("port 1\nstarboard 1\n");
+    assert_eq!(optimize(&Navigator::new(Aim), &turning), turning);
+}
+
+fn command() -> impl Strategy<Value = Command> {
+    let d = -20..20isize;
+    prop_oneof![
+        d.clone().prop_map(Command::Forward),
+        d.clone().prop_map(Command::Down),
+        d.clone().prop_map(Command::Up),
+        d.clone().prop_map(Command::Back),
+        d.prop_map(Command::Hold),
+        Just(Command::Surface),
+        (-3..3isize).prop_map(Command::Port),
+    ]
+}
+
+fn end(name: &str, course: &[Command], start: State) -> State {
+    let mut sub = Sub::new(model::builtin(name).unwrap());
+    sub.state = start;
+    sub.run_commands(course).unwrap();
+    sub.state
+}
+
+proptest! {
+    #[test]
+    fn optimized_courses_end_in_the_same_state(
+        course in prop::collection::vec(command(), 0..200),
+        x in -100..100isize,
+        depth in -100..100isize,
+        aim in -100..100isize,
+    ) {
+        let start = State::new(x, depth, aim);
+        for name in ["direct", "aim"] {
+            let optimized = optimize(model::builtin(name).unwrap().as_ref(), &course);
+            prop_assert!(optimized.len() <= course.len());
+            prop_assert_eq!(end(name, &optimized, start), end(name, &course, start));
+        }
+    }
+}
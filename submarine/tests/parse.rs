@@ -0,0 +1,93 @@
+use std::str::FromStr;
+use proptest::prelude::*;
+use submarine::{Command, Program, Statement};
+
+fn command() -> impl Strategy<Value = Command> {
+    prop_oneof![
+        any::<isize>().prop_map(Command::Forward),
+        any::<isize>().prop_map(Command::Down),
+        any::<isize>().prop_map(Command::Up),
+        any::<isize>().prop_map(Command::Back),
+        Just(Command::Surface),
+        any::<isize>().prop_map(Command::Hold),
+        any::<isize>().prop_map(Command::Port),
+        any::<isize>().prop_map(Command::Starboard),
+    ]
+}
+
+fn program() -> impl Strategy<Value = Program> {
+    program_repeating(any::<usize>())
+}
+
+fn program_repeating(count: impl Strategy<Value = usize> + Clone + 'static) -> impl Strategy<Value = Program> {
+    let statement = command().prop_map(Statement::Command).prop_recursive(4, 64, 8, move |inner| {
+        (count.clone(), prop::collection::vec(inner, 0..8))
+            .prop_map(|(count, body)| Statement::Repeat { count, body })
+    });
+    prop::collection::vec(statement, 0..20).prop_map(|statements| Program { statements })
+}
+
+#[test]
+fn unknown_verbs_are_reported_before_distances() {
+    for line in ["bogus", "bogus 3", "# x"] {
+        let err = Command::from_lines(line).unwrap_err();
+        assert_eq!(err.message, "unknown command", "{}", line);
+    }
+    assert_eq!(Command::from_lines("forward").unwrap_err().message, "expected a distance");
+    assert!(Command::from_lines("surface 3").is_err());
+}
+
+proptest! {
+    #[test]
+    fn parsing_never_panics(s in "\\PC*") {
+        let _ = Command::from_str(&s);
+        let _ = Command::from_lines(&s);
+        let _ = Program::from_str(&s);
+    }
+
+    #[test]
+    fn parsing_command_like_lines_never_panics(s in "((forward|down|up|sideways)? *[-+]?[0-9]{0,22} *[a-z]*\n){0,10}") {
+        let _ = Command::from_lines(&s);
+    }
+
+    #[test]
+    fn reading_arbitrary_bytes_never_panics(bytes in any::<Vec<u8>>()) {
+        let _ = Command::from_reader(&bytes[..]);
+    }
+
+    #[test]
+    fn command_round_trips(command in command()) {
+        prop_assert_eq!(Command::from_str(&command.to_string()).unwrap(), command);
+    }
+
+    #[test]
+    fn commands_round_trip(commands in prop::collection::vec(command(), 0..50)) {
+        let text: String = commands.iter().map(|c| format!("{}\n", c)).collect();
+        prop_assert_eq!(Command::from_lines(&text).unwrap(), commands.clone());
+        prop_assert_eq!(Command::from_reader(text.as_bytes()).unwrap(), commands);
+    }
+
+    #[test]
+    fn parsing_program_like_lines_never_panics(s in "( *(forward|surface|hold|repeat|[{}#])? *[-+]?[0-9]{0,22} *[{}#]? *[a-z]*\n){0,12}") {
+        let _ = Program::from_str(&s);
+    }
+
+    #[test]
+    fn program_round_trips(program in program()) {
+        prop_assert_eq!(Program::from_str(&program.to_string()).unwrap(), program);
+    }
+
+    #[test]
+    fn streamed_programs_run_the_same_commands(program in program_repeating(0..4usize)) {
+        let text = program.to_string();
+        let streamed = Program::stream(text.as_bytes()).collect::<anyhow::Result<Vec<_>>>().unwrap();
+        prop_assert_eq!(streamed, program.commands().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn puzzle_inputs_are_programs(commands in prop::collection::vec(command(), 0..50)) {
+        let text: String = commands.iter().map(|c| format!("{}\n", c)).collect();
+        let program = Program::from_str(&text).unwrap();
+        prop_assert_eq!(program.commands().collect::<Vec<_>>(), commands);
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use proptest::prelude::*;
+use submarine::plan::{self, Limits, PlanError, Target};
+use submarine::{Aim, Command, Direct, State, SteeringModel};
+
+fn target() -> impl Strategy<Value = Target> {
+    (-10_000..10_000isize, -10_000..10_000isize).prop_map(|(x, depth)| Target { x, depth })
+}
+
+fn magnitude(course: &[Command]) -> isize {
+    course.iter().map(|command| match *command {
+        Command::Forward(d) | Command::Down(d) | Command::Up(d) | Command::Back(d) | Command::Hold(d) => d.abs(),
+        Command::Surface | Command::Port(_) | Command::Starboard(_) => 0,
+    }).max().unwrap_or(0)
+}
+
+proptest! {
+    #[test]
+    fn direct_courses_reach_the_target(target in target(), max in 1..100isize) {
+        let limits = Limits { max_magnitude: Some(max), max_commands: None };
+        let course = plan::plan(Box::new(Direct), target, &limits).unwrap();
+        let needed = target.x.unsigned_abs().div_ceil(max as usize) + target.depth.unsigned_abs().div_ceil(max as usize);
+        prop_assert_eq!(course.len(), needed);
+        prop_assert!(magnitude(&course) <= max);
+    }
+
+    #[test]
+    fn aim_courses_reach_the_target(target in target(), max in 1..100isize) {
+        let limits = Limits { max_magnitude: Some(max), max_commands: None };
+        let course = plan::plan(Box::new(Aim), target, &limits).unwrap();
+        prop_assert!(magnitude(&course) <= max);
+    }
+
+    #[test]
+    fn unlimited_aim_courses_take_at_most_three_commands(target in target()) {
+        let course = plan::plan(Box::new(Aim), target, &Limits::default()).unwrap();
+        let needed = match (target.x, target.depth) {
+            (0, 0) => 0,
+            (_, 0) => 1,
+            (x, depth) if x != 0 && depth % x == 0 => 2,
+            _ => 3,
+        };
+        prop_assert_eq!(course.len(), needed);
+    }
+}
+
+#[test]
+fn shallow_legs_shorten_courses_to_awkward_depths() {
+    // 101 is prime, so a single aim either turns or moves 101 in steps of 10
+    let limits = Limits { max_magnitude: Some(10), max_commands: None };
+    let course = plan::plan(Box::new(Aim), Target { x: 20, depth: 101 }, &limits).unwrap();
+    assert_eq!(course.len(), 5);
+}
+
+#[test]
+fn aim_courses_are_as_short_as_any() {
+    // the shortest course to every nearby target, found by trying every command from every state
+    for max in 1..=5 {
+        let mut commands = vec![Command::Surface];
+        for d in 1..=max {
+            commands.extend([Command::Forward(d), Command::Back(d), Command::Down(d), Command::Up(d), Command::Hold(d)]);
+        }
+        let mut shortest = HashMap::new();
+        // states are kept within |x|, |aim| <= 24 and |depth| <= 600
+        let index = |state: State| {
+            let inside = state.x.abs() <= 24 && state.aim.abs() <= 24 && state.depth.abs() <= 600;
+            inside.then(|| (((state.x + 24) * 49 + state.aim + 24) * 1201 + state.depth + 600) as usize)
+        };
+        let mut seen = vec![false; 49 * 49 * 1201];
+        seen[index(State::default()).unwrap()] = true;
+        let mut states = vec![State::default()];
+        for len in 0..=8 {
+            let mut next = Vec::new();
+            for state in states {
+                shortest.entry((state.x, state.depth)).or_insert(len);
+                if len == 8 {
+                    continue;
+                }
+                for &command in &commands {
+                    let Some(next_state) = Aim.step(state, command) else { continue };
+                    if let Some(i) = index(next_state).filter(|&i| !seen[i]) {
+                        seen[i] = true;
+                        next.push(next_state);
+                    }
+                }
+            }
+            states = next;
+        }
+        let limits = Limits { max_magnitude: Some(max), max_commands: None };
+        for ((x, depth), len) in shortest {
+            if x.abs() <= 8 && depth.abs() <= 30 {
+                let course = plan::plan(Box::new(Aim), Target { x, depth }, &limits).unwrap();
+                assert_eq!(course.len(), len, "({}, {}) with moves up to {}", x, depth, max);
+            }
+        }
+    }
+}
+
+#[test]
+fn large_magnitudes_plan_quickly() {
+    // the search tries a few moves and turns from each step, however large they're allowed to be
+    for (x, depth, max) in [(100, 5, 100_000_000), (1_000_000, 999_999_937, 100_000), (-5, isize::MAX, isize::MAX)] {
+        let start = Instant::now();
+        let limits = Limits { max_magnitude: Some(max), max_commands: None };
+        plan::plan(Box::new(Aim), Target { x, depth }, &limits).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(10), "({}, {}) with moves up to {}", x, depth, max);
+    }
+}
+
+#[test]
+fn aim_courses_can_turn_more_than_once() {
+    let target = Target { x: -8, depth: -29 };
+    let limits = Limits { max_magnitude: Some(3), max_commands: Some(5) };
+    assert_eq!(plan::plan(Box::new(Aim), target, &limits).unwrap().len(), 5);
+}
+
+#[test]
+fn courses_respect_the_command_limit() {
+    let limits = Limits { max_magnitude: Some(10), max_commands: Some(10) };
+    let err = plan::plan(Box::new(Direct), Target { x: 100, depth: 50 }, &limits).unwrap_err();
+    assert_eq!(err, PlanError::TooLong { needed: 15, max: 10 });
+    let limits = Limits { max_magnitude: Some(0), max_commands: None };
+    assert_eq!(plan::plan(Box::new(Aim), Target { x: 1, depth: 1 }, &limits).unwrap_err(), PlanError::BadMagnitude(0));
+}
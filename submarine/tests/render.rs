@@ -0,0 +1,28 @@
+use submarine::{render, Command, Direct, Sub, Trace};
+
+fn trace(commands: &str) -> Trace {
+    let mut sub = Sub::new(Box::new(Direct));
+    sub.record();
+    sub.run_commands(&Command::from_lines(commands).unwrap()).unwrap();
+    sub.take_trace().unwrap()
+}
+
+#[test]
+fn ascii_plots_fill_the_requested_size() {
+    let plot = render::ascii(&trace("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n"), 30, 10);
+    let lines: Vec<&str> = plot.lines().collect();
+    // the plot rows, then the axis and its labels
+    assert_eq!(lines.len(), 12);
+    assert!(lines[0].trim_start().starts_with("depth 0 |o"));
+    assert!(lines[9].starts_with("depth 10 |") && lines[9].ends_with('*'));
+    assert!(lines[..10].iter().all(|l| l.chars().count() == "depth 10 |".len() + 30));
+}
+
+#[test]
+fn plots_survive_extreme_dives() {
+    let trace = trace(&format!("down {}\nforward {}\nup {}\n", isize::MAX, isize::MAX, isize::MAX));
+    render::ascii(&trace, 20, 5);
+    render::ascii(&Trace::default(), 0, 0);
+    let svg = render::svg(&trace, 300, 200);
+    assert!(svg.starts_with("<svg ") && svg.trim_end().ends_with("</svg>"));
+}
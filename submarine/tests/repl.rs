@@ -0,0 +1,77 @@
+use std::fs;
+use std::io::Cursor;
+use submarine::repl::{self, Reply, Session};
+use submarine::{Aim, Command, Constraints, Direct, Mode, State, Sub};
+
+fn state(reply: Reply) -> State {
+    match reply {
+        Reply::State { state, .. } => state,
+        other => panic!("expected a state, got {:?}", other),
+    }
+}
+
+#[test]
+fn follows_and_undoes_commands() {
+    let mut session = Session::new(Sub::new(Box::new(Aim)));
+    session.execute("forward 5").unwrap();
+    session.execute("down 5").unwrap();
+    assert_eq!(state(session.execute("forward 8").unwrap()), State::new(13, 40, 5));
+    assert_eq!(state(session.execute("undo").unwrap()), State::new(5, 0, 5));
+    assert_eq!(session.followed(), 2);
+    assert!(session.execute("sideways 3").is_err());
+    assert_eq!(session.sub().state, State::new(5, 0, 5));
+    assert_eq!(state(session.execute("reset").unwrap()), State::default());
+    assert_eq!(session.execute("undo").unwrap(), Reply::Message("nothing to undo".to_string()));
+}
+
+#[test]
+fn help_lists_every_command() {
+    let mut session = Session::new(Sub::new(Box::new(Aim)));
+    let Reply::Message(help) = session.execute("help").unwrap() else { panic!("expected help") };
+    for command in ["forward", "down", "up", "back", "hold", "surface", "port", "starboard"] {
+        assert!(help.contains(command), "help doesn't mention {}", command);
+    }
+}
+
+#[test]
+fn loads_and_saves_courses() {
+    let dir = std::env::temp_dir().join(format!("submarine-repl-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let (course, saved) = (dir.join("course.txt"), dir.join("saved.txt"));
+    fs::write(&course, "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n").unwrap();
+
+    let mut session = Session::new(Sub::new(Box::new(Direct)));
+    session.execute("down 1").unwrap();
+    let loaded = session.execute(&format!("load {}", course.display())).unwrap();
+    assert_eq!(state(loaded), State::new(15, 11, 0));
+    session.execute(&format!("save {}", saved.display())).unwrap();
+    let commands = Command::from_file(saved.to_str().unwrap()).unwrap();
+    assert_eq!(commands.len(), 7);
+    assert_eq!(commands, session.commands().collect::<Vec<_>>());
+    // a load is undone all at once
+    assert_eq!(state(session.execute("undo").unwrap()), State::new(0, 1, 0));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn refused_commands_leave_the_submarine_alone() {
+    let constraints = Constraints { max_depth: Some(10), mode: Mode::Error, ..Constraints::default() };
+    let mut session = Session::new(Sub::new(Box::new(Direct)).with_constraints(constraints));
+    session.execute("down 8").unwrap();
+    assert!(session.execute("down 3").is_err());
+    assert_eq!(session.sub().state.depth, 8);
+    assert_eq!(session.followed(), 1);
+}
+
+#[test]
+fn runs_a_scripted_session() {
+    let mut session = Session::new(Sub::new(Box::new(Aim)));
+    let mut output = Vec::new();
+    repl::run(&mut session, Cursor::new("down 2\nforward 3\nnonsense\nquit\nforward 1\n"), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "> x 0, depth 0, aim 2 after 1 commands");
+    assert_eq!(lines[1], "> x 3, depth 6, aim 2 after 2 commands");
+    assert!(lines[2].starts_with("> error: "));
+    assert_eq!(session.followed(), 2);
+}
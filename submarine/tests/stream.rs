@@ -0,0 +1,52 @@
+use proptest::prelude::*;
+use submarine::{model, Command, Constraints, Mode, Program, Sub};
+
+fn command() -> impl Strategy<Value = Command> {
+    prop_oneof![
+        (-1000..1000isize).prop_map(Command::Forward),
+        (-1000..1000isize).prop_map(Command::Down),
+        (-1000..1000isize).prop_map(Command::Up),
+    ]
+}
+
+fn sub(name: &str) -> Sub {
+    let constraints = Constraints { max_depth: Some(50_000), mode: Mode::Warn, ..Constraints::default() };
+    Sub::new(model::builtin(name).unwrap()).with_constraints(constraints)
+}
+
+proptest! {
+    #[test]
+    fn streaming_matches_the_batch_path(commands in prop::collection::vec(command(), 0..200)) {
+        let text: String = commands.iter().map(|c| format!("{}\n", c)).collect();
+        for name in model::BUILTIN {
+            let mut batch = sub(name);
+            let batch_violations = batch.run_commands(&commands).unwrap();
+
+            let mut streamed = sub(name);
+            let mut violations = Vec::new();
+            let followed = streamed.run_stream(Command::stream(text.as_bytes()), |v| violations.push(v)).unwrap();
+
+            prop_assert_eq!(followed, commands.len());
+            prop_assert_eq!(streamed.state, batch.state);
+            prop_assert_eq!(violations, batch_violations);
+        }
+    }
+}
+
+#[test]
+fn streaming_stops_at_the_first_bad_line() {
+    let mut sub = sub("direct");
+    let err = sub.run_stream(Command::stream("forward 1\nforward x\nforward 2\n".as_bytes()), |_| ()).unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 9: invalid digit found in string at `x`");
+    assert_eq!(sub.state.x, 1);
+}
+
+#[test]
+fn programs_stream_with_comments_and_repeats() {
+    let mut sub = sub("direct");
+    let program = "down 2\n# patrol\n\nrepeat 3 {\n    forward 3\n}\n";
+    assert_eq!(sub.run_stream(Program::stream(program.as_bytes()), |_| ()).unwrap(), 4);
+    assert_eq!((sub.state.x, sub.state.depth), (9, 2));
+    let err = Program::stream("forward 1\nrepeat 2 {\nforward 2\n".as_bytes()).last().unwrap().unwrap_err();
+    assert_eq!(err.to_string(), "line 2, column 1: `repeat` block is never closed at `repeat 2 {`");
+}
@@ -0,0 +1,46 @@
+use submarine::{Aim, Command, Direct, State, Sub};
+
+const SAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+fn trace() -> submarine::Trace {
+    let mut sub = Sub::new(Box::new(Direct));
+    sub.record();
+    sub.run_commands(&Command::from_lines(SAMPLE).unwrap()).unwrap();
+    sub.take_trace().unwrap()
+}
+
+#[test]
+fn records_the_state_after_every_command() {
+    let trace = trace();
+    let depths: Vec<isize> = trace.states().map(|s| s.depth).collect();
+    assert_eq!(depths, [0, 0, 5, 5, 2, 10, 10]);
+    assert_eq!(trace.steps[3].command, Command::Up(3));
+    assert_eq!(trace.steps[3].index, 3);
+}
+
+#[test]
+fn answers_questions_about_the_dive() {
+    let trace = trace();
+    assert_eq!(trace.max_depth().unwrap().index, 4);
+    assert_eq!(trace.first_crossing(5).unwrap().index, 1);
+    assert_eq!(trace.first_crossing(6).unwrap().index, 4);
+    assert!(trace.first_crossing(11).is_none());
+    assert_eq!(trace.time_at_surface(), 1);
+}
+
+#[test]
+fn replays_under_another_model() {
+    let replayed = trace().replay(&Aim).unwrap();
+    assert_eq!(replayed.states().last(), Some(State::new(15, 60, 10)));
+}
+
+#[test]
+fn exports_csv() {
+    let mut csv = Vec::new();
+    trace().write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("index,command,x,depth,aim"));
+    assert_eq!(lines.next(), Some("0,forward 5,5,0,0"));
+    assert_eq!(lines.count(), 5);
+}